@@ -37,7 +37,7 @@ pub struct InitializeExtraAccountMeta<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> InitializeExtraAccountMeta<'info> {
+impl InitializeExtraAccountMeta<'_> {
     pub fn extra_account_meta_list() -> Result<Vec<ExtraAccountMeta>> {
         Ok(vec![
             ExtraAccountMeta::new_with_seeds(
@@ -95,7 +95,7 @@ pub fn process_initialize_extra_account_meta(
     let lamports = Rent::get()?.minimum_balance(account_size);
     let signed_seeds: &[&[&[u8]]] = &[&[
         EXTRA_ACCOUNT_META_LIST_SEED,
-        &mint.as_ref(),
+        mint.as_ref(),
         &[ctx.bumps.extra_account_meta_list],
     ]];
 
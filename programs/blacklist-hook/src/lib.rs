@@ -57,7 +57,7 @@ pub mod blacklist_hook {
                 let amount_le_bytes = amount.to_le_bytes();
                 __private::__global::transfer_hook(program_id, accounts, &amount_le_bytes)
             }
-            _ => return Err(ProgramError::InvalidInstructionData.into()),
+            _ => Err(ProgramError::InvalidInstructionData.into()),
         }
     }
 }
@@ -3,10 +3,7 @@ use anchor_lang::prelude::*;
 use crate::constants::BLACKLIST_ENTRY_SEED;
 use crate::state::BlacklistEntry;
 
-pub fn is_in_blacklist<'info>(
-    blacklist_entry: &AccountInfo<'info>,
-    owner: &Pubkey,
-) -> Result<bool> {
+pub fn is_in_blacklist(blacklist_entry: &AccountInfo, owner: &Pubkey) -> Result<bool> {
     // if not init this account, return false
     if blacklist_entry.owner != &crate::id() || blacklist_entry.data_len() == 0 {
         return Ok(false);
@@ -5,3 +5,13 @@ pub const ACCESS_REGISTRY_SEED: &[u8] = b"access-registry";
 
 #[constant]
 pub const ACCESS_ROLE_SEED: &[u8] = b"access-role";
+
+#[constant]
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+
+#[constant]
+pub const MULTISIG_PROPOSAL_SEED: &[u8] = b"multisig-proposal";
+
+/// Approvals are tracked as a bitmap over the signer indexes
+#[constant]
+pub const MAX_MULTISIG_SIGNERS: u8 = 10;
@@ -81,4 +81,28 @@ pub enum GuardianError {
     OnlyProposedAdminCanAccept,
     #[msg("No pending admin transfer")]
     NoPendingAdminTransfer,
+
+    // Multisig related errors
+    #[msg("Invalid multisig signers")]
+    InvalidMultisigSigners,
+    #[msg("Invalid multisig threshold")]
+    InvalidMultisigThreshold,
+    #[msg("Not a multisig signer")]
+    NotMultisigSigner,
+    #[msg("Proposal already approved by this signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal was created for a previous signer set")]
+    StaleProposal,
+    #[msg("Proposal has not reached the threshold")]
+    ProposalThresholdNotReached,
+    #[msg("Proposal action does not match the instruction")]
+    ProposalActionMismatch,
+    #[msg("Role changes must go through the multisig")]
+    AdminHeldByMultisig,
+    #[msg("Only the proposer can close a proposal of the current signer set")]
+    UnauthorizedProposalClose,
+
+    // Math calculation related errors
+    #[msg("Math overflow")]
+    MathOverflow,
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::Role;
+use crate::state::{MultisigAction, Role};
 
 #[event]
 pub struct AccessRegistryInitialized {
@@ -34,3 +34,45 @@ pub struct AdminTransferCompleted {
     pub previous_admin: Pubkey,
     pub new_admin: Pubkey,
 }
+
+#[event]
+pub struct MultisigUpdated {
+    pub access_registry: Pubkey,
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub executor: Pubkey,
+    pub action: MultisigAction,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub closed_by: Pubkey,
+}
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
 use crate::error::GuardianError;
 use crate::events::AccessRoleAssigned;
-use crate::state::{AccessRegistry, AccessRole, Role};
+use crate::state::{AccessRegistry, AccessRole, Multisig, Role};
 use crate::utils::has_role;
 #[derive(Accounts)]
 #[instruction(role: Role)]
@@ -48,6 +48,12 @@ pub(crate) fn process_assign_role(ctx: Context<AssignRole>, role: Role) -> Resul
         )?,
         GuardianError::InvalidRightToAssignRole
     );
+    // guardian admin role holders lose the shortcut once the multisig holds the admin
+    require!(
+        ctx.accounts.access_registry.admin
+            != Multisig::address(&ctx.accounts.access_registry.key()),
+        GuardianError::AdminHeldByMultisig
+    );
     ctx.accounts.assign_role.role = role;
     ctx.accounts.assign_role.is_initialized = true;
     ctx.accounts.assign_role.owner = ctx.accounts.user.key();
@@ -1,9 +1,13 @@
 mod assign_role;
 mod init_access_registry;
+mod multisig;
+mod multisig_execute;
 mod revoke_role;
 mod transfer_admin;
 
 pub use assign_role::*;
 pub use init_access_registry::*;
+pub use multisig::*;
+pub use multisig_execute::*;
 pub use revoke_role::*;
 pub use transfer_admin::*;
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ACCESS_REGISTRY_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
+use crate::error::GuardianError;
+use crate::events::{MultisigUpdated, ProposalApproved, ProposalClosed, ProposalCreated};
+use crate::state::{AccessRegistry, Multisig, MultisigAction, MultisigProposal};
+
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ACCESS_REGISTRY_SEED],
+        bump = access_registry.bump,
+        constraint = access_registry.is_initialized @ GuardianError::AccessRegistryNotInitialized,
+        constraint = access_registry.admin == admin.key() @ GuardianError::UnauthorizedGuardianAdmin,
+    )]
+    pub access_registry: Account<'info, AccessRegistry>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Multisig::SIZE,
+        seeds = [MULTISIG_SEED, access_registry.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, multisig.access_registry.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::SIZE,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), multisig.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.access_registry.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    pub closer: Signer<'info>,
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.access_registry.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// CHECK: creator of the proposal, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// Hands the registry admin to an M-of-N signer set, the multisig PDA becomes `access_registry.admin`
+pub(crate) fn process_set_multisig(
+    ctx: Context<SetMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate_signers(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.is_initialized = true;
+    multisig.bump = ctx.bumps.multisig;
+    multisig.access_registry = ctx.accounts.access_registry.key();
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.signer_set_seqno = multisig.signer_set_seqno.wrapping_add(1);
+
+    // no single key keeps the admin rights once the signer set is in place
+    let access_registry = &mut ctx.accounts.access_registry;
+    access_registry.admin = multisig.key();
+    access_registry.pending_admin = Pubkey::default();
+
+    emit!(MultisigUpdated {
+        access_registry: access_registry.key(),
+        multisig: multisig.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+    });
+    Ok(())
+}
+
+/// Opens a proposal for an admin operation, the proposer's approval is counted right away
+pub(crate) fn process_create_proposal(
+    ctx: Context<CreateProposal>,
+    action: MultisigAction,
+) -> Result<()> {
+    action.validate()?;
+    let multisig = &mut ctx.accounts.multisig;
+    let id = multisig.next_proposal_id;
+    multisig.next_proposal_id = multisig
+        .next_proposal_id
+        .checked_add(1)
+        .ok_or(GuardianError::MathOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.is_initialized = true;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.multisig = multisig.key();
+    proposal.id = id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action.clone();
+    proposal.signer_set_seqno = multisig.signer_set_seqno;
+    proposal.approvals = 0;
+    proposal.approve(multisig, &ctx.accounts.proposer.key())?;
+
+    emit!(ProposalCreated {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        id,
+        proposer: ctx.accounts.proposer.key(),
+        action,
+    });
+    Ok(())
+}
+
+pub(crate) fn process_approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approve(&ctx.accounts.multisig, &ctx.accounts.signer.key())?;
+
+    emit!(ProposalApproved {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: proposal.approval_count(),
+    });
+    Ok(())
+}
+
+/// Drops a proposal and refunds its rent, the proposer can close it any time and anyone once it is stale
+pub(crate) fn process_close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        ctx.accounts.closer.key() == proposal.proposer
+            || proposal.signer_set_seqno != ctx.accounts.multisig.signer_set_seqno,
+        GuardianError::UnauthorizedProposalClose
+    );
+
+    emit!(ProposalClosed {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        id: proposal.id,
+        closed_by: ctx.accounts.closer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,275 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED,
+};
+use crate::error::GuardianError;
+use crate::events::{
+    AccessRoleAssigned, AccessRoleRevoked, AdminTransferProposed, MultisigUpdated, ProposalExecuted,
+};
+use crate::state::{AccessRegistry, AccessRole, Multisig, MultisigAction, MultisigProposal, Role};
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct ExecuteAssignRole<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        bump = access_registry.bump,
+        constraint = access_registry.admin == multisig.key() @ GuardianError::UnauthorizedGuardianAdmin,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [MULTISIG_SEED, access_registry.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    /// CHECK: creator of the proposal, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// CHECK: checked against the proposal
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = AccessRole::SIZE,
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), user.key().as_ref(), role.to_seed().as_slice()],
+        bump
+    )]
+    pub assign_role: Box<Account<'info, AccessRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRevokeRole<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        bump = access_registry.bump,
+        constraint = access_registry.admin == multisig.key() @ GuardianError::UnauthorizedGuardianAdmin,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [MULTISIG_SEED, access_registry.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    /// CHECK: creator of the proposal, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = executor,
+        constraint = revoke_role.is_initialized @ GuardianError::AccessRoleNotInitialized,
+        constraint = revoke_role.access_registry == access_registry.key() @ GuardianError::MustBeAccessRegistry
+    )]
+    pub revoke_role: Box<Account<'info, AccessRole>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposeNewAdmin<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ACCESS_REGISTRY_SEED],
+        bump = access_registry.bump,
+        constraint = access_registry.admin == multisig.key() @ GuardianError::UnauthorizedGuardianAdmin,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [MULTISIG_SEED, access_registry.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    /// CHECK: creator of the proposal, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// CHECK: This is the proposed new admin, no signature required
+    pub proposed_admin: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteUpdateSigners<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        bump = access_registry.bump,
+        constraint = access_registry.admin == multisig.key() @ GuardianError::UnauthorizedGuardianAdmin,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, access_registry.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    /// CHECK: creator of the proposal, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub(crate) fn process_execute_assign_role(
+    ctx: Context<ExecuteAssignRole>,
+    role: Role,
+) -> Result<()> {
+    let action = MultisigAction::AssignRole {
+        user: ctx.accounts.user.key(),
+        role,
+    };
+    ctx.accounts
+        .proposal
+        .check_executable(&ctx.accounts.multisig, &action)?;
+    require!(
+        !ctx.accounts.assign_role.is_initialized,
+        GuardianError::AccessRoleAlreadyInitialized
+    );
+
+    ctx.accounts.assign_role.role = role;
+    ctx.accounts.assign_role.is_initialized = true;
+    ctx.accounts.assign_role.owner = ctx.accounts.user.key();
+    ctx.accounts.assign_role.access_registry = ctx.accounts.access_registry.key();
+    ctx.accounts.assign_role.bump = ctx.bumps.assign_role;
+    emit!(AccessRoleAssigned {
+        role,
+        address: ctx.accounts.user.key(),
+    });
+    emit!(ProposalExecuted {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        id: ctx.accounts.proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action,
+    });
+    Ok(())
+}
+
+pub(crate) fn process_execute_revoke_role(ctx: Context<ExecuteRevokeRole>) -> Result<()> {
+    let action = MultisigAction::RevokeRole {
+        access_role: ctx.accounts.revoke_role.key(),
+    };
+    ctx.accounts
+        .proposal
+        .check_executable(&ctx.accounts.multisig, &action)?;
+
+    // close the access role account
+    ctx.accounts.revoke_role.is_initialized = false;
+    emit!(AccessRoleRevoked {
+        role: ctx.accounts.revoke_role.role,
+        address: ctx.accounts.revoke_role.owner,
+    });
+    emit!(ProposalExecuted {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        id: ctx.accounts.proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action,
+    });
+    Ok(())
+}
+
+/// The proposed admin still has to accept with `accept_admin_transfer`
+pub(crate) fn process_execute_propose_new_admin(
+    ctx: Context<ExecuteProposeNewAdmin>,
+) -> Result<()> {
+    let action = MultisigAction::ProposeNewAdmin {
+        proposed_admin: ctx.accounts.proposed_admin.key(),
+    };
+    ctx.accounts
+        .proposal
+        .check_executable(&ctx.accounts.multisig, &action)?;
+
+    let access_registry = &mut ctx.accounts.access_registry;
+    require!(
+        access_registry.pending_admin != ctx.accounts.proposed_admin.key(),
+        GuardianError::ProposedAdminAlreadySet
+    );
+    require!(
+        access_registry.admin != ctx.accounts.proposed_admin.key(),
+        GuardianError::ProposedAdminIsCurrentAdmin
+    );
+    access_registry.pending_admin = ctx.accounts.proposed_admin.key();
+
+    emit!(AdminTransferProposed {
+        access_registry: access_registry.key(),
+        current_admin: ctx.accounts.multisig.key(),
+        proposed_admin: ctx.accounts.proposed_admin.key(),
+    });
+    emit!(ProposalExecuted {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        id: ctx.accounts.proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action,
+    });
+    Ok(())
+}
+
+/// Replaces the signer set and threshold, approvals given to open proposals become void
+pub(crate) fn process_execute_update_signers(ctx: Context<ExecuteUpdateSigners>) -> Result<()> {
+    let action = ctx.accounts.proposal.action.clone();
+    let MultisigAction::UpdateSigners { signers, threshold } = action.clone() else {
+        return err!(GuardianError::ProposalActionMismatch);
+    };
+    ctx.accounts
+        .proposal
+        .check_executable(&ctx.accounts.multisig, &action)?;
+    Multisig::validate_signers(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.signer_set_seqno = multisig.signer_set_seqno.wrapping_add(1);
+
+    emit!(MultisigUpdated {
+        access_registry: ctx.accounts.access_registry.key(),
+        multisig: multisig.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+    });
+    emit!(ProposalExecuted {
+        multisig: multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        id: ctx.accounts.proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action,
+    });
+    Ok(())
+}
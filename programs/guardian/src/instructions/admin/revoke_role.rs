@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::constants::ACCESS_REGISTRY_SEED;
 use crate::error::GuardianError;
 use crate::events::AccessRoleRevoked;
-use crate::state::{AccessRegistry, AccessRole, Role};
+use crate::state::{AccessRegistry, AccessRole, Multisig, Role};
 use crate::utils::has_role;
 
 #[derive(Accounts)]
@@ -40,6 +40,12 @@ pub(crate) fn process_revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
         )?,
         GuardianError::InvalidRightToRevokeRole
     );
+    // guardian admin role holders lose the shortcut once the multisig holds the admin
+    require!(
+        ctx.accounts.access_registry.admin
+            != Multisig::address(&ctx.accounts.access_registry.key()),
+        GuardianError::AdminHeldByMultisig
+    );
     // close the access role account
     ctx.accounts.revoke_role.is_initialized = false;
     emit!(AccessRoleRevoked {
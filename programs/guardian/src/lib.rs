@@ -39,4 +39,40 @@ pub mod guardian {
     pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
         process_accept_admin_transfer(ctx)
     }
+
+    pub fn set_multisig(
+        ctx: Context<SetMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        process_set_multisig(ctx, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: MultisigAction) -> Result<()> {
+        process_create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        process_approve_proposal(ctx)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        process_close_proposal(ctx)
+    }
+
+    pub fn execute_update_signers(ctx: Context<ExecuteUpdateSigners>) -> Result<()> {
+        process_execute_update_signers(ctx)
+    }
+
+    pub fn execute_assign_role(ctx: Context<ExecuteAssignRole>, role: Role) -> Result<()> {
+        process_execute_assign_role(ctx, role)
+    }
+
+    pub fn execute_revoke_role(ctx: Context<ExecuteRevokeRole>) -> Result<()> {
+        process_execute_revoke_role(ctx)
+    }
+
+    pub fn execute_propose_new_admin(ctx: Context<ExecuteProposeNewAdmin>) -> Result<()> {
+        process_execute_propose_new_admin(ctx)
+    }
 }
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use std::fmt::Display;
 
-#[derive(Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub enum Role {
     GuardianAdmin,
 
@@ -21,6 +21,11 @@ pub enum Role {
     UsduUnstaker,
     VaultAdmin,
     RewardDistributor,
+    PriceFeedUpdater,
+    ParticipantManager,
+    Pauser,
+    LossReporter,
+    ActionCanceller,
 }
 
 impl Role {
@@ -49,6 +54,11 @@ impl Display for Role {
             Role::UsduUnstaker => "usdu_unstaker",
             Role::VaultAdmin => "vault_admin",
             Role::RewardDistributor => "reward_distributor",
+            Role::PriceFeedUpdater => "price_feed_updater",
+            Role::ParticipantManager => "participant_manager",
+            Role::Pauser => "pauser",
+            Role::LossReporter => "loss_reporter",
+            Role::ActionCanceller => "action_canceller",
         };
         write!(f, "{}", role_str)
     }
@@ -1,3 +1,5 @@
 mod access;
+mod multisig;
 
 pub use access::*;
+pub use multisig::*;
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_MULTISIG_SIGNERS, MULTISIG_SEED};
+use crate::error::GuardianError;
+use crate::state::Role;
+
+/// M-of-N signer set that holds the access registry admin
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Multisig {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub access_registry: Pubkey,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Bumped on every signer set change, so approvals given to the old set are void
+    pub signer_set_seqno: u32,
+    pub next_proposal_id: u64,
+}
+
+impl Multisig {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn address(access_registry: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[MULTISIG_SEED, access_registry.as_ref()], &crate::ID).0
+    }
+
+    pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS as usize,
+            GuardianError::InvalidMultisigSigners
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                *signer != Pubkey::default() && !signers[..i].contains(signer),
+                GuardianError::InvalidMultisigSigners
+            );
+        }
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            GuardianError::InvalidMultisigThreshold
+        );
+        Ok(())
+    }
+
+    pub fn signer_index(&self, signer: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|s| s == signer)
+            .ok_or(GuardianError::NotMultisigSigner.into())
+    }
+}
+
+/// Admin operation that needs the multisig threshold of approvals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum MultisigAction {
+    AssignRole {
+        user: Pubkey,
+        role: Role,
+    },
+    RevokeRole {
+        access_role: Pubkey,
+    },
+    ProposeNewAdmin {
+        proposed_admin: Pubkey,
+    },
+    UpdateSigners {
+        #[max_len(MAX_MULTISIG_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
+
+impl MultisigAction {
+    /// Checks the action parameters when the proposal is created
+    pub fn validate(&self) -> Result<()> {
+        if let MultisigAction::UpdateSigners { signers, threshold } = self {
+            Multisig::validate_signers(signers, *threshold)?;
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MultisigProposal {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub multisig: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub signer_set_seqno: u32,
+    /// Bit `i` is set once `multisig.signers[i]` has approved
+    pub approvals: u16,
+}
+
+impl MultisigProposal {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn approve(&mut self, multisig: &Multisig, signer: &Pubkey) -> Result<()> {
+        require!(
+            self.signer_set_seqno == multisig.signer_set_seqno,
+            GuardianError::StaleProposal
+        );
+        let bit = 1u16 << multisig.signer_index(signer)?;
+        require!(
+            self.approvals & bit == 0,
+            GuardianError::ProposalAlreadyApproved
+        );
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+
+    /// Checks the proposal can be executed with the current signer set and carries `action`
+    pub fn check_executable(&self, multisig: &Multisig, action: &MultisigAction) -> Result<()> {
+        require!(
+            self.signer_set_seqno == multisig.signer_set_seqno,
+            GuardianError::StaleProposal
+        );
+        require!(
+            self.approval_count() >= multisig.threshold,
+            GuardianError::ProposalThresholdNotReached
+        );
+        require!(
+            self.action == *action,
+            GuardianError::ProposalActionMismatch
+        );
+        Ok(())
+    }
+}
@@ -24,8 +24,8 @@ use crate::state::SusduConfig;
 // Blacklist hook program ID
 pub const BLACKLIST_HOOK_PROGRAM_ID: &str = "2qLbEAAHfPwJ7vixH7BuSdfJzvn9LTavfM69B5Q149DZ";
 
-fn get_mint_with_len_extension<'a, T: Extension + VariableLenPack>(
-    mint_account: &'a mut AccountInfo,
+fn get_mint_with_len_extension<T: Extension + VariableLenPack>(
+    mint_account: &mut AccountInfo,
 ) -> Result<T> {
     let mint_data = mint_account.data.borrow();
     let mint_with_extension = StateWithExtensions::<StateMint>::unpack(&mint_data)?;
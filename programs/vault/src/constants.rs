@@ -9,6 +9,9 @@ pub const VAULT_STATE_SEED: &[u8] = b"vault-state";
 #[constant]
 pub const VAULT_COOLDOWN_SEED: &[u8] = b"vault-cooldown";
 
+#[constant]
+pub const VAULT_COOLDOWN_COUNTER_SEED: &[u8] = b"vault-cooldown-counter";
+
 #[constant]
 pub const VAULT_SUSDU_TOKEN_ACCOUNT_SEED: &[u8] = b"vault-susdu-token-approval";
 
@@ -21,6 +24,36 @@ pub const VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED: &[u8] = b"vault-stake-pool-u
 #[constant]
 pub const VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED: &[u8] = b"vault-silo-usdu";
 
+#[constant]
+pub const VAULT_COLLATERAL_CONFIG_SEED: &[u8] = b"vault-collateral-config";
+
+#[constant]
+pub const VAULT_CUSTODIAN_SEED: &[u8] = b"vault-custodian";
+
+#[constant]
+pub const VAULT_PRIMARY_PARTICIPANT_SEED: &[u8] = b"vault-primary-participant";
+
+#[constant]
+pub const VAULT_PRICE_FEED_SEED: &[u8] = b"vault-price-feed";
+
+#[constant]
+pub const VAULT_DEAD_SHARES_SEED: &[u8] = b"vault-dead-shares";
+
+#[constant]
+pub const VAULT_PENDING_ACTION_SEED: &[u8] = b"vault-pending-action";
+
+#[constant]
+pub const VAULT_ORDER_NONCE_SEED: &[u8] = b"vault-order-nonce";
+
+#[constant]
+pub const DEFAULT_RATE_LIMIT_WINDOW_SLOTS: u64 = 1;
+
+#[constant]
+pub const MINT_ORDER_DOMAIN: &[u8] = b"usdu-mint-order";
+
+#[constant]
+pub const REDEEM_ORDER_DOMAIN: &[u8] = b"usdu-redeem-order";
+
 #[cfg(not(feature = "testnet"))]
 #[constant]
 pub const MIN_COOLDOWN_DURATION: u64 = 60 * 60;
@@ -34,3 +67,41 @@ pub const DEFAULT_COOLDOWN_DURATION: u64 = 7 * 24 * 60 * 60;
 
 #[constant]
 pub const MAX_COOLDOWN_DURATION: u64 = 30 * 24 * 60 * 60;
+
+#[cfg(not(feature = "testnet"))]
+#[constant]
+pub const DEFAULT_ACTION_DELAY: u64 = 2 * 24 * 60 * 60;
+
+#[cfg(feature = "testnet")]
+#[constant]
+pub const DEFAULT_ACTION_DELAY: u64 = 0;
+
+#[constant]
+pub const MAX_ACTION_DELAY: u64 = 30 * 24 * 60 * 60;
+
+#[constant]
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+
+#[constant]
+pub const MAX_KEEPER_TIP_BPS: u16 = 100;
+
+#[constant]
+pub const MAX_REWARD_FEE_BPS: u16 = 2_000;
+
+#[constant]
+pub const DEFAULT_MAX_LOSS_PER_REPORT_BPS: u16 = 100;
+
+#[constant]
+pub const DEFAULT_MAX_ACCOUNTING_DRIFT: u64 = 10 * 10_u64.pow(6);
+
+#[constant]
+pub const MAX_LOSS_PER_REPORT_BPS: u16 = 500;
+
+#[constant]
+pub const MIN_LOSS_REPORT_INTERVAL: u64 = 24 * 60 * 60;
+
+#[constant]
+pub const DEFAULT_MAX_PRICE_DEVIATION_BPS: u16 = 50;
+
+#[constant]
+pub const DEFAULT_MAX_PRICE_AGE: u64 = 60 * 60;
@@ -17,6 +17,8 @@ pub enum VaultError {
     InitialDepositAlreadyAdded,
     #[msg("Insufficient initial deposit")]
     InsufficientInitialDeposit,
+    #[msg("Account already migrated")]
+    AccountAlreadyMigrated,
 
     // Admin transfer related errors
     #[msg("Proposed admin already set")]
@@ -44,6 +46,14 @@ pub enum VaultError {
     #[msg("No pending admin transfer")]
     NoPendingAdminTransfer,
 
+    // Pause related errors
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("Operation paused")]
+    OperationPaused,
+    #[msg("Only the admin can unpause")]
+    OnlyAdminCanUnpause,
+
     // Vault related errors
     #[msg("Collateral mismatch")]
     CollateralMismatch,
@@ -55,6 +65,12 @@ pub enum VaultError {
     InvalidPostAmountInput,
     #[msg("Max deposit exceeded")]
     MaxDepositExceeded,
+    #[msg("Max mint exceeded")]
+    MaxMintExceeded,
+    #[msg("Max withdraw exceeded")]
+    MaxWithdrawExceeded,
+    #[msg("Max redeem exceeded")]
+    MaxRedeemExceeded,
     #[msg("Insufficient min shares")]
     InsufficientMinShares,
     #[msg("Invalid usdu token")]
@@ -62,6 +78,96 @@ pub enum VaultError {
     #[msg("Invalid susdu token")]
     InvalidSusduToken,
 
+    // Collateral registry related errors
+    #[msg("Collateral already registered")]
+    CollateralAlreadyRegistered,
+    #[msg("Collateral not registered")]
+    CollateralNotRegistered,
+    #[msg("Unsupported collateral token")]
+    UnsupportedCollateralToken,
+    #[msg("Collateral decimals mismatch")]
+    CollateralDecimalsMismatch,
+    #[msg("Collateral mint disabled")]
+    CollateralMintDisabled,
+    #[msg("Collateral redeem disabled")]
+    CollateralRedeemDisabled,
+    #[msg("Collateral retired")]
+    CollateralRetired,
+    #[msg("Collateral mint limit exceeded")]
+    CollateralMintLimitExceeded,
+    #[msg("Collateral redeem limit exceeded")]
+    CollateralRedeemLimitExceeded,
+
+    // Custodian registry related errors
+    #[msg("Custodian already registered")]
+    CustodianAlreadyRegistered,
+    #[msg("Custodian not registered")]
+    CustodianNotRegistered,
+    #[msg("Custodian not active")]
+    CustodianNotActive,
+    #[msg("Invalid routing weight")]
+    InvalidRoutingWeight,
+
+    // Primary participant related errors
+    #[msg("Participant mint not allowed")]
+    ParticipantMintNotAllowed,
+    #[msg("Participant redeem not allowed")]
+    ParticipantRedeemNotAllowed,
+    #[msg("Participant expired")]
+    ParticipantExpired,
+
+    // Price feed related errors
+    #[msg("Price feed not initialized")]
+    PriceFeedNotInitialized,
+    #[msg("Stale price feed")]
+    StalePriceFeed,
+    #[msg("Invalid price")]
+    InvalidPrice,
+    #[msg("Invalid price deviation")]
+    InvalidPriceDeviation,
+    #[msg("Price deviation too large")]
+    PriceDeviationTooLarge,
+
+    // Rate limit related errors
+    #[msg("Invalid rate limit window")]
+    InvalidRateLimitWindow,
+    #[msg("Mint rate limit exceeded")]
+    MintRateLimitExceeded,
+    #[msg("Redeem rate limit exceeded")]
+    RedeemRateLimitExceeded,
+
+    // Timelock related errors
+    #[msg("Pending action not initialized")]
+    PendingActionNotInitialized,
+    #[msg("Pending action is not ready to execute")]
+    ActionNotReady,
+    #[msg("Action delay too long")]
+    ActionDelayTooLong,
+    #[msg("Lowering an action delay must be queued")]
+    ActionDelayDecreaseNotQueued,
+    #[msg("Missing account required by the action")]
+    MissingActionAccount,
+    #[msg("Invalid action token mint")]
+    InvalidActionTokenMint,
+    #[msg("Invalid action receiver token account")]
+    InvalidActionReceiverTokenAccount,
+
+    // Signed order related errors
+    #[msg("Invalid ed25519 instruction")]
+    InvalidEd25519Instruction,
+    #[msg("Order signer mismatch")]
+    OrderSignerMismatch,
+    #[msg("Order message mismatch")]
+    OrderMessageMismatch,
+    #[msg("Order accounts mismatch")]
+    OrderAccountsMismatch,
+    #[msg("Order expired")]
+    OrderExpired,
+    #[msg("Order nonce already used")]
+    OrderNonceAlreadyUsed,
+    #[msg("Invalid order nonce bitmap")]
+    InvalidOrderNonceBitmap,
+
     // Vault account related errors
     #[msg("Invalid vault stake pool usdu token account")]
     InvalidVaultStakePoolUsduTokenAccount,
@@ -99,6 +205,16 @@ pub enum VaultError {
     CooldownDurationTooShort,
     #[msg("Cooldown duration too long")]
     CooldownDurationTooLong,
+    #[msg("Cooldown disabled")]
+    CooldownDisabled,
+    #[msg("Cooldown enabled")]
+    CooldownEnabled,
+    #[msg("Insufficient cooldown amount")]
+    InsufficientCooldownAmount,
+    #[msg("Cooldown not empty")]
+    CooldownNotEmpty,
+    #[msg("Keeper tip too high")]
+    KeeperTipTooHigh,
 
     // USDU related errors
     #[msg("Amount must be greater than zero")]
@@ -125,8 +241,34 @@ pub enum VaultError {
     InsufficientUsduInSilo,
     #[msg("Insufficient usdu to cover all active cooldowns")]
     InsufficientUsduForCooldowns,
+    #[msg("Usdu config total supply does not match the mint supply")]
+    UsduSupplyMismatch,
+    #[msg("Susdu config total supply does not match the mint supply")]
+    SusduSupplyMismatch,
     #[msg("Still vesting")]
     StillVesting,
+    #[msg("Vesting period too short")]
+    VestingPeriodTooShort,
+    #[msg("Vesting period too long")]
+    VestingPeriodTooLong,
+    #[msg("Reward fee too high")]
+    RewardFeeTooHigh,
+    #[msg("Invalid treasury usdu token account")]
+    InvalidTreasuryUsduTokenAccount,
+    #[msg("Invalid max loss per report")]
+    InvalidMaxLossPerReport,
+    #[msg("Loss exceeds max loss per report")]
+    LossExceedsMaxPerReport,
+    #[msg("Loss reported too soon after the previous report")]
+    LossReportTooSoon,
+    #[msg("Accounting drift exceeded")]
+    AccountingDriftExceeded,
+    #[msg("Invalid decimals offset")]
+    InvalidDecimalsOffset,
+    #[msg("Decimals offset can only be set before the initial deposit")]
+    DecimalsOffsetLocked,
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
 
     // SUSDU related errors
     #[msg("Insufficient susdu")]
@@ -139,6 +281,10 @@ pub enum VaultError {
     InvalidUnstakeSusduAmount,
     #[msg("Invalid preview deposit susdu amount")]
     InvalidPreviewDepositSusduAmount,
+    #[msg("Invalid mint susdu amount")]
+    InvalidMintSusduAmount,
+    #[msg("Invalid preview withdraw susdu amount")]
+    InvalidPreviewWithdrawSusduAmount,
     #[msg("Invalid locked susdu token account owner")]
     InvalidLockedSusduTokenAccountOwner,
     #[msg("Invalid locked susdu token account amount")]
@@ -1,16 +1,66 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{CollateralStatus, VaultAction, VaultActionType};
+
 #[event]
 pub struct CooldownAdjusted {
     pub vault_config: Pubkey,
     pub cooldown_duration: u64,
 }
 
+#[event]
+pub struct NoCooldownModeUpdated {
+    pub vault_config: Pubkey,
+    pub no_cooldown_mode: bool,
+}
+
+#[event]
+pub struct VestingPeriodUpdated {
+    pub vault_config: Pubkey,
+    pub old_vesting_period: u64,
+    pub vesting_period: u64,
+}
+
+#[event]
+pub struct DecimalsOffsetUpdated {
+    pub vault_config: Pubkey,
+    pub decimals_offset: u8,
+}
+
+#[event]
+pub struct RewardFeeUpdated {
+    pub vault_config: Pubkey,
+    pub reward_fee_bps: u16,
+    pub treasury_usdu_token_account: Pubkey,
+}
+
+#[event]
+pub struct MaxLossPerReportUpdated {
+    pub vault_config: Pubkey,
+    pub max_loss_per_report_bps: u16,
+}
+
+#[event]
+pub struct LossReported {
+    pub vault_config: Pubkey,
+    pub reporter: Pubkey,
+    pub usdu_amount: u64,
+    pub total_staked_usdu_supply: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct KeeperTipUpdated {
+    pub vault_config: Pubkey,
+    pub keeper_tip_bps: u16,
+}
+
 #[event]
 pub struct RedeemUsduWithdrawCollateralEvent {
     pub benefactor: Pubkey,
     pub beneficiary: Pubkey,
     pub fund: Pubkey,
+    pub custodian: Pubkey,
     pub collateral_amount: u64,
     pub usdu_amount: u64,
 }
@@ -20,10 +70,120 @@ pub struct DepositCollateralMintUsduEvent {
     pub benefactor: Pubkey,
     pub beneficiary: Pubkey,
     pub fund: Pubkey,
+    pub custodian: Pubkey,
     pub collateral_amount: u64,
     pub usdu_amount: u64,
 }
 
+#[event]
+pub struct CollateralAdded {
+    pub collateral_config: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub max_mint_per_tx: u64,
+    pub max_redeem_per_tx: u64,
+}
+
+#[event]
+pub struct CollateralStatusUpdated {
+    pub collateral_config: Pubkey,
+    pub mint: Pubkey,
+    pub previous_status: CollateralStatus,
+    pub status: CollateralStatus,
+}
+
+#[event]
+pub struct CollateralLimitsUpdated {
+    pub collateral_config: Pubkey,
+    pub mint: Pubkey,
+    pub max_mint_per_tx: u64,
+    pub max_redeem_per_tx: u64,
+}
+
+#[event]
+pub struct CustodianAdded {
+    pub custodian: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub fund: Pubkey,
+    pub routing_weight_bps: Option<u16>,
+}
+
+#[event]
+pub struct CustodianUpdated {
+    pub custodian: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub fund: Pubkey,
+    pub is_active: bool,
+    pub routing_weight_bps: Option<u16>,
+}
+
+#[event]
+pub struct PrimaryParticipantUpdated {
+    pub primary_participant: Pubkey,
+    pub wallet: Pubkey,
+    pub can_mint: bool,
+    pub can_redeem: bool,
+    pub expiry: Option<u64>,
+}
+
+#[event]
+pub struct Paused {
+    pub vault_config: Pubkey,
+    pub authority: Pubkey,
+    pub flags: u8,
+    pub paused_flags: u8,
+}
+
+#[event]
+pub struct Unpaused {
+    pub vault_config: Pubkey,
+    pub authority: Pubkey,
+    pub flags: u8,
+    pub paused_flags: u8,
+}
+
+#[event]
+pub struct RateLimitsUpdated {
+    pub vault_config: Pubkey,
+    pub max_mint_per_window: u64,
+    pub max_redeem_per_window: u64,
+    pub rate_limit_window_slots: u64,
+}
+
+#[event]
+pub struct CollateralPriceBandUpdated {
+    pub collateral_config: Pubkey,
+    pub mint: Pubkey,
+    pub max_price_deviation_bps: u16,
+    pub max_price_age: u64,
+}
+
+#[event]
+pub struct PriceFeedUpdated {
+    pub price_feed: Pubkey,
+    pub mint: Pubkey,
+    pub updater: Pubkey,
+    pub price: u64,
+    pub decimals: u8,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MintOrderFilled {
+    pub benefactor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub filler: Pubkey,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct RedeemOrderFilled {
+    pub benefactor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub filler: Pubkey,
+    pub nonce: u64,
+}
+
 #[event]
 pub struct AdminTransferProposed {
     pub vault_config: Pubkey,
@@ -54,6 +214,13 @@ pub struct VaultStateInitialized {
     pub admin: Pubkey,
 }
 
+#[event]
+pub struct VaultAccountMigrated {
+    pub account: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+}
+
 #[event]
 pub struct VaultTokenAccountInitialized {
     pub vault_state: Pubkey,
@@ -64,7 +231,9 @@ pub struct VaultTokenAccountInitialized {
 pub struct UsduRewardDistributed {
     pub vault_config: Pubkey,
     pub distributor: Pubkey,
-    pub amount: u64,
+    pub gross_amount: u64,
+    pub fee_amount: u64,
+    pub net_amount: u64,
     pub total_staked_usdu_supply: u64,
     pub timestamp: u64,
 }
@@ -86,6 +255,60 @@ pub struct UsduWithdrawn {
     pub timestamp: u64,
 }
 
+#[event]
+pub struct CooldownCancelled {
+    pub vault_config: Pubkey,
+    pub cooldown: Pubkey,
+    pub caller: Pubkey,
+    pub usdu_amount: u64,
+    pub susdu_amount: u64,
+    pub remaining_usdu_amount: u64,
+}
+
+#[event]
+pub struct CooldownClosed {
+    pub vault_config: Pubkey,
+    pub cooldown: Pubkey,
+    pub caller: Pubkey,
+}
+
+#[event]
+pub struct LegacyCooldownMigrated {
+    pub vault_config: Pubkey,
+    pub legacy_cooldown: Pubkey,
+    pub cooldown: Pubkey,
+    pub owner: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct UsduWithdrawnByKeeper {
+    pub vault_config: Pubkey,
+    pub cooldown: Pubkey,
+    pub keeper: Pubkey,
+    pub owner: Pubkey,
+    pub usdu_amount: u64,
+    pub keeper_tip: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct CooldownReceiverUpdated {
+    pub cooldown: Pubkey,
+    pub owner: Pubkey,
+    pub old_underlying_token_account: Pubkey,
+    pub new_underlying_token_account: Pubkey,
+}
+
+#[event]
+pub struct SusduUnstakedDirect {
+    pub vault_config: Pubkey,
+    pub caller: Pubkey,
+    pub receiver: Pubkey,
+    pub susdu_amount: u64,
+    pub usdu_amount: u64,
+}
+
 #[event]
 pub struct RedistributedSusdu {
     pub vault_config: Pubkey,
@@ -95,6 +318,66 @@ pub struct RedistributedSusdu {
     pub timestamp: u64,
 }
 
+#[event]
+pub struct InitialDeadSharesMinted {
+    pub vault_config: Pubkey,
+    pub dead_shares_token_account: Pubkey,
+    pub dead_shares: u64,
+}
+
+#[event]
+pub struct VaultAccountingSynced {
+    pub vault_config: Pubkey,
+    pub authority: Pubkey,
+    pub old_total_staked_usdu_supply: u64,
+    pub new_total_staked_usdu_supply: u64,
+    pub old_total_cooldown_usdu_amount: u64,
+    pub new_total_cooldown_usdu_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ActionQueued {
+    pub vault_config: Pubkey,
+    pub pending_action: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: VaultAction,
+    pub eta: u64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub vault_config: Pubkey,
+    pub pending_action: Pubkey,
+    pub id: u64,
+    pub canceller: Pubkey,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub vault_config: Pubkey,
+    pub pending_action: Pubkey,
+    pub id: u64,
+    pub executor: Pubkey,
+    pub action: VaultAction,
+}
+
+#[event]
+pub struct MaxAccountingDriftUpdated {
+    pub vault_config: Pubkey,
+    pub old_max_accounting_drift: u64,
+    pub max_accounting_drift: u64,
+}
+
+#[event]
+pub struct ActionDelayUpdated {
+    pub vault_config: Pubkey,
+    pub action_type: VaultActionType,
+    pub old_delay: u64,
+    pub delay: u64,
+}
+
 #[event]
 pub struct EmergencyWithdrawal {
     pub vault_config: Pubkey,
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, DEFAULT_MAX_PRICE_AGE, DEFAULT_MAX_PRICE_DEVIATION_BPS,
+    VAULT_COLLATERAL_CONFIG_SEED, VAULT_CONFIG_SEED,
+};
+use crate::error::VaultError;
+use crate::events::{
+    CollateralAdded, CollateralLimitsUpdated, CollateralPriceBandUpdated, CollateralStatusUpdated,
+};
+use crate::state::{CollateralConfig, CollateralStatus, VaultConfig};
+use crate::utils::{has_role_or_admin, is_supported_mint};
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct AddCollateral<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+    pub collateral_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CollateralConfig::SIZE,
+        seeds = [VAULT_COLLATERAL_CONFIG_SEED, collateral_token.key().as_ref()],
+        bump,
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollateral<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+    #[account(
+        mut,
+        seeds = [VAULT_COLLATERAL_CONFIG_SEED, collateral_config.mint.as_ref()],
+        bump = collateral_config.bump,
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+}
+
+pub fn process_add_collateral(
+    ctx: Context<AddCollateral>,
+    max_mint_per_tx: u64,
+    max_redeem_per_tx: u64,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        !ctx.accounts.collateral_config.is_initialized,
+        VaultError::CollateralAlreadyRegistered
+    );
+    require!(
+        is_supported_mint(&ctx.accounts.collateral_token)?,
+        VaultError::UnsupportedCollateralToken
+    );
+
+    let collateral_config = &mut ctx.accounts.collateral_config;
+    collateral_config.is_initialized = true;
+    collateral_config.bump = ctx.bumps.collateral_config;
+    collateral_config.mint = ctx.accounts.collateral_token.key();
+    collateral_config.decimals = ctx.accounts.collateral_token.decimals;
+    collateral_config.status = CollateralStatus::Active;
+    collateral_config.max_mint_per_tx = max_mint_per_tx;
+    collateral_config.max_redeem_per_tx = max_redeem_per_tx;
+    collateral_config.max_price_deviation_bps = DEFAULT_MAX_PRICE_DEVIATION_BPS;
+    collateral_config.max_price_age = DEFAULT_MAX_PRICE_AGE;
+
+    emit!(CollateralAdded {
+        collateral_config: collateral_config.key(),
+        mint: collateral_config.mint,
+        decimals: collateral_config.decimals,
+        max_mint_per_tx,
+        max_redeem_per_tx,
+    });
+
+    Ok(())
+}
+
+pub fn process_set_collateral_status(
+    ctx: Context<UpdateCollateral>,
+    status: CollateralStatus,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+
+    let collateral_config = &mut ctx.accounts.collateral_config;
+    require!(
+        collateral_config.is_initialized,
+        VaultError::CollateralNotRegistered
+    );
+    // retired collateral can only be redeemed, it is never brought back
+    require!(
+        collateral_config.status != CollateralStatus::Retired,
+        VaultError::CollateralRetired
+    );
+
+    let previous_status = collateral_config.status;
+    collateral_config.status = status;
+
+    if previous_status != status {
+        emit!(CollateralStatusUpdated {
+            collateral_config: collateral_config.key(),
+            mint: collateral_config.mint,
+            previous_status,
+            status,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn process_set_collateral_limits(
+    ctx: Context<UpdateCollateral>,
+    max_mint_per_tx: u64,
+    max_redeem_per_tx: u64,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+
+    let collateral_config = &mut ctx.accounts.collateral_config;
+    require!(
+        collateral_config.is_initialized,
+        VaultError::CollateralNotRegistered
+    );
+
+    collateral_config.max_mint_per_tx = max_mint_per_tx;
+    collateral_config.max_redeem_per_tx = max_redeem_per_tx;
+
+    emit!(CollateralLimitsUpdated {
+        collateral_config: collateral_config.key(),
+        mint: collateral_config.mint,
+        max_mint_per_tx,
+        max_redeem_per_tx,
+    });
+
+    Ok(())
+}
+
+pub fn process_set_collateral_price_band(
+    ctx: Context<UpdateCollateral>,
+    max_price_deviation_bps: u16,
+    max_price_age: u64,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        max_price_deviation_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+        VaultError::InvalidPriceDeviation
+    );
+
+    let collateral_config = &mut ctx.accounts.collateral_config;
+    require!(
+        collateral_config.is_initialized,
+        VaultError::CollateralNotRegistered
+    );
+
+    collateral_config.max_price_deviation_bps = max_price_deviation_bps;
+    collateral_config.max_price_age = max_price_age;
+
+    emit!(CollateralPriceBandUpdated {
+        collateral_config: collateral_config.key(),
+        mint: collateral_config.mint,
+        max_price_deviation_bps,
+        max_price_age,
+    });
+
+    Ok(())
+}
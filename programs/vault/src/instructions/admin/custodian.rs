@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, VAULT_COLLATERAL_CONFIG_SEED, VAULT_CONFIG_SEED, VAULT_CUSTODIAN_SEED,
+};
+use crate::error::VaultError;
+use crate::events::{CustodianAdded, CustodianUpdated};
+use crate::state::{CollateralConfig, Custodian, VaultConfig};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct AddCustodian<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+    #[account(
+        seeds = [VAULT_COLLATERAL_CONFIG_SEED, collateral_config.mint.as_ref()],
+        bump = collateral_config.bump,
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+    /// CHECK: custodian wallet, no need to checked
+    pub fund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Custodian::SIZE,
+        seeds = [VAULT_CUSTODIAN_SEED, collateral_config.mint.as_ref(), fund.key().as_ref()],
+        bump,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCustodian<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+    #[account(
+        mut,
+        seeds = [VAULT_CUSTODIAN_SEED, custodian.collateral_mint.as_ref(), custodian.custodian.as_ref()],
+        bump = custodian.bump,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+}
+
+fn check_routing_weight(routing_weight_bps: Option<u16>) -> Result<()> {
+    if let Some(routing_weight_bps) = routing_weight_bps {
+        require!(
+            routing_weight_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+            VaultError::InvalidRoutingWeight
+        );
+    }
+    Ok(())
+}
+
+pub fn process_add_custodian(
+    ctx: Context<AddCustodian>,
+    routing_weight_bps: Option<u16>,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        ctx.accounts.collateral_config.is_initialized,
+        VaultError::CollateralNotRegistered
+    );
+    require!(
+        !ctx.accounts.custodian.is_initialized,
+        VaultError::CustodianAlreadyRegistered
+    );
+    check_routing_weight(routing_weight_bps)?;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.is_initialized = true;
+    custodian.bump = ctx.bumps.custodian;
+    custodian.collateral_mint = ctx.accounts.collateral_config.mint;
+    custodian.custodian = ctx.accounts.fund.key();
+    custodian.is_active = true;
+    custodian.routing_weight_bps = routing_weight_bps;
+
+    emit!(CustodianAdded {
+        custodian: custodian.key(),
+        collateral_mint: custodian.collateral_mint,
+        fund: custodian.custodian,
+        routing_weight_bps,
+    });
+
+    Ok(())
+}
+
+pub fn process_update_custodian(
+    ctx: Context<UpdateCustodian>,
+    is_active: bool,
+    routing_weight_bps: Option<u16>,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    let custodian = &mut ctx.accounts.custodian;
+    require!(custodian.is_initialized, VaultError::CustodianNotRegistered);
+    check_routing_weight(routing_weight_bps)?;
+
+    custodian.is_active = is_active;
+    custodian.routing_weight_bps = routing_weight_bps;
+
+    emit!(CustodianUpdated {
+        custodian: custodian.key(),
+        collateral_mint: custodian.collateral_mint,
+        fund: custodian.custodian,
+        is_active,
+        routing_weight_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::VAULT_CONFIG_SEED;
+use crate::error::VaultError;
+use crate::events::DecimalsOffsetUpdated;
+use crate::state::{VaultConfig, MAX_DECIMALS_OFFSET};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct SetDecimalsOffset<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Sets the ERC4626 decimals offset, only before the initial deposit
+pub fn process_set_decimals_offset(
+    ctx: Context<SetDecimalsOffset>,
+    decimals_offset: u8,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        decimals_offset <= MAX_DECIMALS_OFFSET,
+        VaultError::InvalidDecimalsOffset
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    // the offset is baked into the share price once shares exist
+    require!(
+        !vault_config.has_initial_deposit,
+        VaultError::DecimalsOffsetLocked
+    );
+
+    vault_config.decimals_offset = decimals_offset;
+
+    emit!(DecimalsOffsetUpdated {
+        vault_config: vault_config.key(),
+        decimals_offset,
+    });
+
+    Ok(())
+}
@@ -4,11 +4,12 @@ use anchor_spl::token_2022::{transfer_checked, TransferChecked};
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::constants::{
-    VAULT_CONFIG_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+    BASIS_POINTS_DENOMINATOR, VAULT_CONFIG_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STATE_SEED,
 };
 use crate::error::VaultError;
 use crate::events::UsduRewardDistributed;
-use crate::state::{VaultConfig, VaultState};
+use crate::state::{VaultConfig, VaultState, PAUSE_REWARD};
 use crate::utils::has_role_or_admin;
 
 use susdu::constants::SUSDU_CONFIG_SEED;
@@ -48,6 +49,14 @@ pub struct DistributeUsduReward<'info> {
         token::token_program = token_program,
     )]
     pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// Only required while a reward fee is set
+    #[account(
+        mut,
+        address = vault_config.treasury_usdu_token_account @ VaultError::InvalidTreasuryUsduTokenAccount,
+        token::mint = usdu_token,
+        token::token_program = token_program,
+    )]
+    pub treasury_usdu_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         seeds = [ACCESS_REGISTRY_SEED],
@@ -80,6 +89,7 @@ pub fn process_distribute_usdu_reward(
     ctx: Context<DistributeUsduReward>,
     usdu_amount: u64,
 ) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_REWARD)?;
     require!(
         has_role_or_admin(
             &ctx.accounts.vault_config,
@@ -111,20 +121,53 @@ pub fn process_distribute_usdu_reward(
         VaultError::InsufficientUsduBalance
     );
     let vault_config = &mut ctx.accounts.vault_config;
-    require!(
-        vault_config.get_unvested_amount() == 0,
-        VaultError::StillVesting
-    );
     require!(
         ctx.accounts.susdu_config.total_supply
             >= 10u64.pow(ctx.accounts.susdu_token.decimals.into()),
         VaultError::SusduTotalSupplyTooLow
     );
 
-    // update vault config
-    vault_config.vesting_amount = usdu_amount + vault_config.get_unvested_amount();
+    // split the protocol fee off the gross reward
+    let fee_amount = (usdu_amount as u128)
+        .checked_mul(vault_config.reward_fee_bps as u128)
+        .ok_or(VaultError::MathOverflow)?
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .ok_or(VaultError::MathOverflow)? as u64;
+    let net_amount = usdu_amount - fee_amount;
+    require!(net_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+
+    // update vault config, a top-up during vesting merges the unvested remainder and restarts the schedule
+    let unvested_amount = vault_config.get_unvested_amount();
+    vault_config.vesting_amount = net_amount
+        .checked_add(unvested_amount)
+        .ok_or(VaultError::MathOverflow)?;
     vault_config.last_distribution_timestamp = Clock::get()?.unix_timestamp as u64;
-    vault_config.total_staked_usdu_supply = vault_config.total_staked_usdu_supply + usdu_amount;
+    vault_config.total_staked_usdu_supply = vault_config
+        .total_staked_usdu_supply
+        .checked_add(net_amount)
+        .ok_or(VaultError::MathOverflow)?;
+
+    // transfer the fee to the treasury usdu token account
+    if fee_amount > 0 {
+        let treasury_usdu_token_account = ctx
+            .accounts
+            .treasury_usdu_token_account
+            .as_ref()
+            .ok_or(VaultError::InvalidTreasuryUsduTokenAccount)?;
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.caller_usdu_token_account.to_account_info(),
+                    to: treasury_usdu_token_account.to_account_info(),
+                    authority: ctx.accounts.caller.to_account_info(),
+                    mint: ctx.accounts.usdu_token.to_account_info(),
+                },
+            ),
+            fee_amount,
+            ctx.accounts.usdu_token.decimals,
+        )?;
+    }
 
     // transfer usdu to vault slio usdu token account
     transfer_checked(
@@ -140,7 +183,7 @@ pub fn process_distribute_usdu_reward(
                 mint: ctx.accounts.usdu_token.to_account_info(),
             },
         ),
-        usdu_amount,
+        net_amount,
         ctx.accounts.usdu_token.decimals,
     )?;
 
@@ -148,7 +191,9 @@ pub fn process_distribute_usdu_reward(
     emit!(UsduRewardDistributed {
         vault_config: vault_config.key(),
         distributor: ctx.accounts.caller.key(),
-        amount: usdu_amount,
+        gross_amount: usdu_amount,
+        fee_amount,
+        net_amount,
         total_staked_usdu_supply: vault_config.total_staked_usdu_supply,
         timestamp: vault_config.last_distribution_timestamp,
     });
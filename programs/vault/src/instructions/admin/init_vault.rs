@@ -2,13 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::constants::{
-    MAX_COOLDOWN_DURATION, MIN_COOLDOWN_DURATION, VAULT_CONFIG_SEED,
-    VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
-    VAULT_SUSDU_TOKEN_ACCOUNT_SEED, VAULT_USDU_TOKEN_ACCOUNT_SEED,
+    DEFAULT_ACTION_DELAY, DEFAULT_MAX_ACCOUNTING_DRIFT, DEFAULT_MAX_LOSS_PER_REPORT_BPS,
+    DEFAULT_RATE_LIMIT_WINDOW_SLOTS, MAX_COOLDOWN_DURATION, MIN_COOLDOWN_DURATION,
+    VAULT_CONFIG_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED, VAULT_SUSDU_TOKEN_ACCOUNT_SEED,
+    VAULT_USDU_TOKEN_ACCOUNT_SEED,
 };
 use crate::error::VaultError;
 use crate::events::{VaultConfigInitialized, VaultStateInitialized, VaultTokenAccountInitialized};
-use crate::state::{VaultConfig, VaultState};
+use crate::state::{VaultConfig, VaultState, VAULT_ACTION_TYPE_COUNT, VESTING_PERIOD};
 use guardian::constants::ACCESS_REGISTRY_SEED;
 use guardian::state::AccessRegistry;
 
@@ -191,7 +193,7 @@ pub fn process_init_vault_config(
 ) -> Result<()> {
     let vault_config = &mut ctx.accounts.vault_config;
     require!(
-        vault_config.is_initialized == false,
+        !vault_config.is_initialized,
         VaultError::ConfigAlreadyInitialized
     );
 
@@ -216,6 +218,13 @@ pub fn process_init_vault_config(
     vault_config.last_distribution_timestamp = 0;
     vault_config.total_staked_usdu_supply = 0;
     vault_config.total_cooldown_usdu_amount = 0;
+    vault_config.max_mint_per_window = u64::MAX;
+    vault_config.max_redeem_per_window = u64::MAX;
+    vault_config.rate_limit_window_slots = DEFAULT_RATE_LIMIT_WINDOW_SLOTS;
+    vault_config.vesting_period = VESTING_PERIOD;
+    vault_config.max_loss_per_report_bps = DEFAULT_MAX_LOSS_PER_REPORT_BPS;
+    vault_config.action_delays = [DEFAULT_ACTION_DELAY; VAULT_ACTION_TYPE_COUNT];
+    vault_config.max_accounting_drift = DEFAULT_MAX_ACCOUNTING_DRIFT;
 
     // Emit event
     emit!(VaultConfigInitialized {
@@ -233,7 +242,7 @@ pub fn process_init_vault_config(
 pub(crate) fn process_init_vault_state(ctx: Context<InitVaultState>) -> Result<()> {
     let vault_state = &mut ctx.accounts.vault_state;
     require!(
-        vault_state.is_initialized == false,
+        !vault_state.is_initialized,
         VaultError::StateAlreadyInitialized
     );
     vault_state.is_initialized = true;
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::constants::{VAULT_CONFIG_SEED, VAULT_STATE_SEED};
+use crate::error::VaultError;
+use crate::events::VaultAccountMigrated;
+use crate::state::{LegacyVaultConfig, LegacyVaultState, VaultConfig, VaultState};
+
+#[derive(Accounts)]
+pub struct MigrateVaultConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: still in the legacy layout, deserialized in the instruction
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vault_config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVaultState<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ VaultError::InvalidVaultAdminAuthority,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    /// CHECK: still in the legacy layout, deserialized in the instruction
+    #[account(
+        mut,
+        seeds = [VAULT_STATE_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vault_state: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Reads the legacy account body, checking it was not migrated yet
+fn read_legacy<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: &[u8],
+    size: usize,
+) -> Result<T> {
+    require!(
+        account.data_len() < size,
+        VaultError::AccountAlreadyMigrated
+    );
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && &data[..8] == discriminator,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(T::deserialize(&mut &data[8..])?)
+}
+
+/// Grows the account to `size`, the admin tops up the rent
+fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    admin: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(size);
+    let lamports = rent.saturating_sub(account.lamports());
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: admin.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    account.realloc(size, true)?;
+    Ok(())
+}
+
+/// Grows a `VaultConfig` created before fields were appended to it and fills them with defaults
+pub fn process_migrate_vault_config(ctx: Context<MigrateVaultConfig>) -> Result<()> {
+    let account = ctx.accounts.vault_config.to_account_info();
+    let legacy: LegacyVaultConfig =
+        read_legacy(&account, &VaultConfig::DISCRIMINATOR, VaultConfig::SIZE)?;
+    require!(legacy.is_initialized, VaultError::ConfigNotInitialized);
+    require!(
+        legacy.admin == ctx.accounts.admin.key(),
+        VaultError::InvalidVaultAdminAuthority
+    );
+
+    let old_size = account.data_len();
+    realloc_account(
+        &account,
+        VaultConfig::SIZE,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+    )?;
+    let vault_config = VaultConfig::from(legacy);
+    vault_config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(VaultAccountMigrated {
+        account: account.key(),
+        old_size: old_size as u64,
+        new_size: VaultConfig::SIZE as u64,
+    });
+    Ok(())
+}
+
+/// Grows a `VaultState` created before the rate limit counters were appended, run after `migrate_vault_config`
+pub fn process_migrate_vault_state(ctx: Context<MigrateVaultState>) -> Result<()> {
+    let account = ctx.accounts.vault_state.to_account_info();
+    let legacy: LegacyVaultState =
+        read_legacy(&account, &VaultState::DISCRIMINATOR, VaultState::SIZE)?;
+    require!(legacy.is_initialized, VaultError::StateNotInitialized);
+
+    let old_size = account.data_len();
+    realloc_account(
+        &account,
+        VaultState::SIZE,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+    )?;
+    let vault_state = VaultState::from(legacy);
+    vault_state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(VaultAccountMigrated {
+        account: account.key(),
+        old_size: old_size as u64,
+        new_size: VaultState::SIZE as u64,
+    });
+    Ok(())
+}
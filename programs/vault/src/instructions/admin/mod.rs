@@ -1,13 +1,35 @@
-mod adjust_cooldown;
+mod collateral;
+mod custodian;
+mod decimals_offset;
 mod distribute_usdu_reward;
-mod emergency;
 mod init_vault;
+mod migrate;
+mod pause;
+mod price_feed;
+mod primary_participant;
+mod rate_limit;
 mod redistribute_locked;
+mod report_loss;
+mod reward_fee;
+mod sync_accounting;
+mod timelock;
 mod transfer_admin;
+mod vesting;
 
-pub use adjust_cooldown::*;
+pub use collateral::*;
+pub use custodian::*;
+pub use decimals_offset::*;
 pub use distribute_usdu_reward::*;
-pub use emergency::*;
 pub use init_vault::*;
+pub use migrate::*;
+pub use pause::*;
+pub use price_feed::*;
+pub use primary_participant::*;
+pub use rate_limit::*;
 pub use redistribute_locked::*;
+pub use report_loss::*;
+pub use reward_fee::*;
+pub use sync_accounting::*;
+pub use timelock::*;
 pub use transfer_admin::*;
+pub use vesting::*;
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::VAULT_CONFIG_SEED;
+use crate::error::VaultError;
+use crate::events::{Paused, Unpaused};
+use crate::state::{VaultConfig, PAUSE_ALL};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::Pauser.to_seed().as_slice()],
+        bump = pauser.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub pauser: Box<Account<'info, AccessRole>>,
+}
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ VaultError::OnlyAdminCanUnpause,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+}
+
+/// Pausers can only add flags, lifting them is left to the admin
+pub fn process_pause(ctx: Context<Pause>, flags: u8) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.pauser.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::Pauser
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        VaultError::InvalidPauseFlags
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.paused_flags |= flags;
+
+    emit!(Paused {
+        vault_config: vault_config.key(),
+        authority: ctx.accounts.authority.key(),
+        flags,
+        paused_flags: vault_config.paused_flags,
+    });
+
+    Ok(())
+}
+
+pub fn process_unpause(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        VaultError::InvalidPauseFlags
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.paused_flags &= !flags;
+
+    emit!(Unpaused {
+        vault_config: vault_config.key(),
+        authority: ctx.accounts.admin.key(),
+        flags,
+        paused_flags: vault_config.paused_flags,
+    });
+
+    Ok(())
+}
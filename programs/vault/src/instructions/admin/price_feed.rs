@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{VAULT_COLLATERAL_CONFIG_SEED, VAULT_CONFIG_SEED, VAULT_PRICE_FEED_SEED};
+use crate::error::VaultError;
+use crate::events::PriceFeedUpdated;
+use crate::state::{CollateralConfig, PriceFeed, VaultConfig};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::PriceFeedUpdater.to_seed().as_slice()],
+        bump = price_feed_updater.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub price_feed_updater: Box<Account<'info, AccessRole>>,
+    #[account(
+        seeds = [VAULT_COLLATERAL_CONFIG_SEED, collateral_config.mint.as_ref()],
+        bump = collateral_config.bump,
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PriceFeed::SIZE,
+        seeds = [VAULT_PRICE_FEED_SEED, collateral_config.mint.as_ref()],
+        bump,
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_update_price_feed(
+    ctx: Context<UpdatePriceFeed>,
+    price: u64,
+    decimals: u8,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.price_feed_updater.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::PriceFeedUpdater
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        ctx.accounts.collateral_config.is_initialized,
+        VaultError::CollateralNotRegistered
+    );
+    require!(price > 0, VaultError::InvalidPrice);
+
+    let timestamp = Clock::get()?.unix_timestamp as u64;
+    let price_feed = &mut ctx.accounts.price_feed;
+    if !price_feed.is_initialized {
+        price_feed.is_initialized = true;
+        price_feed.bump = ctx.bumps.price_feed;
+        price_feed.mint = ctx.accounts.collateral_config.mint;
+    }
+    price_feed.price = price;
+    price_feed.decimals = decimals;
+    price_feed.updated_at = timestamp;
+
+    emit!(PriceFeedUpdated {
+        price_feed: price_feed.key(),
+        mint: price_feed.mint,
+        updater: ctx.accounts.authority.key(),
+        price,
+        decimals,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{VAULT_CONFIG_SEED, VAULT_PRIMARY_PARTICIPANT_SEED};
+use crate::error::VaultError;
+use crate::events::PrimaryParticipantUpdated;
+use crate::state::{PrimaryParticipant, VaultConfig};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct SetPrimaryParticipant<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::ParticipantManager.to_seed().as_slice()],
+        bump = participant_manager.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub participant_manager: Box<Account<'info, AccessRole>>,
+    /// CHECK: participant wallet, no need to checked
+    pub wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PrimaryParticipant::SIZE,
+        seeds = [VAULT_PRIMARY_PARTICIPANT_SEED, wallet.key().as_ref()],
+        bump,
+    )]
+    pub primary_participant: Box<Account<'info, PrimaryParticipant>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or updates the allowlist entry of a wallet, clearing both flags revokes it
+pub fn process_set_primary_participant(
+    ctx: Context<SetPrimaryParticipant>,
+    can_mint: bool,
+    can_redeem: bool,
+    expiry: Option<u64>,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.participant_manager.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::ParticipantManager
+        )?,
+        VaultError::UnauthorizedRole
+    );
+
+    let primary_participant = &mut ctx.accounts.primary_participant;
+    if !primary_participant.is_initialized {
+        primary_participant.is_initialized = true;
+        primary_participant.bump = ctx.bumps.primary_participant;
+        primary_participant.wallet = ctx.accounts.wallet.key();
+    }
+    primary_participant.can_mint = can_mint;
+    primary_participant.can_redeem = can_redeem;
+    primary_participant.expiry = expiry;
+
+    emit!(PrimaryParticipantUpdated {
+        primary_participant: primary_participant.key(),
+        wallet: primary_participant.wallet,
+        can_mint,
+        can_redeem,
+        expiry,
+    });
+
+    Ok(())
+}
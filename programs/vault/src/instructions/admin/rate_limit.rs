@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::VAULT_CONFIG_SEED;
 use crate::error::VaultError;
-use crate::events::CooldownAdjusted;
+use crate::events::RateLimitsUpdated;
 use crate::state::VaultConfig;
 use crate::utils::has_role_or_admin;
 
@@ -9,11 +10,15 @@ use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
 use guardian::{AccessRegistry, AccessRole, Role};
 
 #[derive(Accounts)]
-pub struct AdjustCooldown<'info> {
-    #[account(mut)]
-    pub vault_config: Box<Account<'info, VaultConfig>>,
+pub struct SetRateLimits<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
     #[account(
         seeds = [ACCESS_REGISTRY_SEED],
         seeds::program = guardian::id(),
@@ -26,11 +31,15 @@ pub struct AdjustCooldown<'info> {
         seeds::program = guardian::id(),
     )]
     pub vault_admin: Box<Account<'info, AccessRole>>,
-    pub system_program: Program<'info, System>,
 }
 
-pub fn process_adjust_cooldown(ctx: Context<AdjustCooldown>, cooldown_duration: u64) -> Result<()> {
-    // Check if the caller has VaultAdmin role or is an admin
+/// Caps the USDU minted and redeemed within each window of `rate_limit_window_slots` slots
+pub fn process_set_rate_limits(
+    ctx: Context<SetRateLimits>,
+    max_mint_per_window: u64,
+    max_redeem_per_window: u64,
+    rate_limit_window_slots: u64,
+) -> Result<()> {
     require!(
         has_role_or_admin(
             &ctx.accounts.vault_config,
@@ -41,33 +50,22 @@ pub fn process_adjust_cooldown(ctx: Context<AdjustCooldown>, cooldown_duration:
         )?,
         VaultError::UnauthorizedRole
     );
-
-    // Validate cooldown duration is within allowed range
-    require!(
-        cooldown_duration >= crate::constants::MIN_COOLDOWN_DURATION,
-        VaultError::CooldownDurationTooShort
-    );
     require!(
-        cooldown_duration <= crate::constants::MAX_COOLDOWN_DURATION,
-        VaultError::CooldownDurationTooLong
+        rate_limit_window_slots > 0,
+        VaultError::InvalidRateLimitWindow
     );
 
     let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.max_mint_per_window = max_mint_per_window;
+    vault_config.max_redeem_per_window = max_redeem_per_window;
+    vault_config.rate_limit_window_slots = rate_limit_window_slots;
 
-    // Store the old cooldown duration for comparison
-    let old_cooldown_duration = vault_config.cooldown_duration;
-
-    // Update the cooldown duration
-    vault_config.cooldown_duration = cooldown_duration;
-
-    // Only emit the event if the cooldown duration has actually changed
-    // This prevents misleading information and unnecessary event emissions
-    if old_cooldown_duration != cooldown_duration {
-        emit!(CooldownAdjusted {
-            vault_config: ctx.accounts.vault_config.key(),
-            cooldown_duration,
-        });
-    }
+    emit!(RateLimitsUpdated {
+        vault_config: vault_config.key(),
+        max_mint_per_window,
+        max_redeem_per_window,
+        rate_limit_window_slots,
+    });
 
     Ok(())
 }
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, MAX_LOSS_PER_REPORT_BPS, MIN_LOSS_REPORT_INTERVAL, VAULT_CONFIG_SEED,
+    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::events::{LossReported, MaxLossPerReportUpdated};
+use crate::state::{VaultConfig, VaultState};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+
+use usdu::constants::USDU_CONFIG_SEED;
+use usdu::cpi::{accounts::RedeemUsdu, redeem_usdu};
+use usdu::program::Usdu;
+use usdu::state::UsduConfig;
+
+#[derive(Accounts)]
+pub struct ReportLoss<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+        has_one = vault_stake_pool_usdu_token_account,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_stake_pool_usdu_token_account_bump,
+        token::mint = usdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [USDU_CONFIG_SEED],
+        bump = usdu_config.bump,
+        seeds::program = usdu::id(),
+    )]
+    pub usdu_config: Box<Account<'info, UsduConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), vault_config.key().as_ref(), Role::UsduRedeemer.to_seed().as_slice()],
+        bump = usdu_redeemer.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub usdu_redeemer: Box<Account<'info, AccessRole>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::LossReporter.to_seed().as_slice()],
+        bump = loss_reporter.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub loss_reporter: Box<Account<'info, AccessRole>>,
+
+    #[account(
+        mut,
+        address = vault_config.usdu @ VaultError::InvalidUsduToken,
+    )]
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub usdu_program: Program<'info, Usdu>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxLossPerReport<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Burns a strategy loss out of the stake pool, lowering the SUSDU share price
+pub fn process_report_loss(ctx: Context<ReportLoss>, usdu_amount: u64) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.loss_reporter.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::LossReporter
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+    require!(
+        ctx.accounts.vault_stake_pool_usdu_token_account.amount >= usdu_amount,
+        VaultError::InsufficientStakePoolUsdu
+    );
+
+    // 1. reports are spaced out so the cap bounds the loss per interval, not per transaction
+    let vault_config = &mut ctx.accounts.vault_config;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        now >= vault_config
+            .last_loss_report_timestamp
+            .saturating_add(MIN_LOSS_REPORT_INTERVAL),
+        VaultError::LossReportTooSoon
+    );
+
+    // 2. cap the loss to a share of the vested assets, unvested rewards are never written down
+    let max_loss = (vault_config.total_assets() as u128)
+        .checked_mul(vault_config.max_loss_per_report_bps as u128)
+        .ok_or(VaultError::MathOverflow)?
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(
+        usdu_amount as u128 <= max_loss,
+        VaultError::LossExceedsMaxPerReport
+    );
+
+    // 3. update total_staked_usdu_supply
+    vault_config.total_staked_usdu_supply = vault_config
+        .total_staked_usdu_supply
+        .checked_sub(usdu_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    vault_config.last_loss_report_timestamp = now;
+
+    // 4. burn the lost usdu from the stake pool, so the usdu supply shrinks with it
+    let config_bump = &[vault_config.bump];
+    let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
+    redeem_usdu(
+        CpiContext::new_with_signer(
+            ctx.accounts.usdu_program.to_account_info(),
+            RedeemUsdu {
+                caller: vault_config.to_account_info(),
+                access_registry: ctx.accounts.access_registry.to_account_info(),
+                access_role: ctx.accounts.usdu_redeemer.to_account_info(),
+                usdu_config: ctx.accounts.usdu_config.to_account_info(),
+                usdu_token: ctx.accounts.usdu_token.to_account_info(),
+                caller_token_account: ctx
+                    .accounts
+                    .vault_stake_pool_usdu_token_account
+                    .to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            config_seeds,
+        ),
+        usdu_amount,
+    )?;
+
+    emit!(LossReported {
+        vault_config: vault_config.key(),
+        reporter: ctx.accounts.authority.key(),
+        usdu_amount,
+        total_staked_usdu_supply: vault_config.total_staked_usdu_supply,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn process_set_max_loss_per_report(
+    ctx: Context<SetMaxLossPerReport>,
+    max_loss_per_report_bps: u16,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        max_loss_per_report_bps <= MAX_LOSS_PER_REPORT_BPS,
+        VaultError::InvalidMaxLossPerReport
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.max_loss_per_report_bps = max_loss_per_report_bps;
+
+    emit!(MaxLossPerReportUpdated {
+        vault_config: vault_config.key(),
+        max_loss_per_report_bps,
+    });
+
+    Ok(())
+}
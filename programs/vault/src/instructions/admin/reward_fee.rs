@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::{MAX_REWARD_FEE_BPS, VAULT_CONFIG_SEED};
+use crate::error::VaultError;
+use crate::events::RewardFeeUpdated;
+use crate::state::VaultConfig;
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct SetRewardFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        token::mint = vault_config.usdu,
+    )]
+    pub treasury_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Share of each reward distribution sent to the treasury instead of SUSDU holders
+pub fn process_set_reward_fee(ctx: Context<SetRewardFee>, reward_fee_bps: u16) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        reward_fee_bps <= MAX_REWARD_FEE_BPS,
+        VaultError::RewardFeeTooHigh
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.reward_fee_bps = reward_fee_bps;
+    vault_config.treasury_usdu_token_account = ctx.accounts.treasury_usdu_token_account.key();
+
+    emit!(RewardFeeUpdated {
+        vault_config: vault_config.key(),
+        reward_fee_bps,
+        treasury_usdu_token_account: vault_config.treasury_usdu_token_account,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::{
+    VAULT_CONFIG_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::events::VaultAccountingSynced;
+use crate::state::{VaultConfig, VaultState};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct SyncVaultAccounting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+        has_one = vault_stake_pool_usdu_token_account,
+        has_one = vault_silo_usdu_token_account,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        seeds = [VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_stake_pool_usdu_token_account_bump,
+    )]
+    pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_silo_usdu_token_account_bump,
+    )]
+    pub vault_silo_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Resets `total_staked_usdu_supply` and `total_cooldown_usdu_amount` to the stake pool and silo
+/// balances, refusing if either counter drifted by more than `vault_config.max_accounting_drift`
+pub fn process_sync_vault_accounting(ctx: Context<SyncVaultAccounting>) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    let old_total_staked_usdu_supply = vault_config.total_staked_usdu_supply;
+    let old_total_cooldown_usdu_amount = vault_config.total_cooldown_usdu_amount;
+    let new_total_staked_usdu_supply = ctx.accounts.vault_stake_pool_usdu_token_account.amount;
+    let new_total_cooldown_usdu_amount = ctx.accounts.vault_silo_usdu_token_account.amount;
+
+    // 1. check the drift of each counter is within the bound set through the timelock
+    let max_drift = vault_config.max_accounting_drift;
+    require!(
+        old_total_staked_usdu_supply.abs_diff(new_total_staked_usdu_supply) <= max_drift,
+        VaultError::AccountingDriftExceeded
+    );
+    require!(
+        old_total_cooldown_usdu_amount.abs_diff(new_total_cooldown_usdu_amount) <= max_drift,
+        VaultError::AccountingDriftExceeded
+    );
+    // 2. the stake pool must still cover the rewards that are vesting
+    require!(
+        new_total_staked_usdu_supply >= vault_config.get_unvested_amount(),
+        VaultError::InsufficientStakePoolUsdu
+    );
+
+    // 3. update counters
+    vault_config.total_staked_usdu_supply = new_total_staked_usdu_supply;
+    vault_config.total_cooldown_usdu_amount = new_total_cooldown_usdu_amount;
+
+    emit!(VaultAccountingSynced {
+        vault_config: vault_config.key(),
+        authority: ctx.accounts.authority.key(),
+        old_total_staked_usdu_supply,
+        new_total_staked_usdu_supply,
+        old_total_cooldown_usdu_amount,
+        new_total_cooldown_usdu_amount,
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,497 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::constants::{
+    MAX_ACTION_DELAY, VAULT_CONFIG_SEED, VAULT_PENDING_ACTION_SEED, VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::events::{
+    ActionCancelled, ActionDelayUpdated, ActionExecuted, ActionQueued, CooldownAdjusted,
+    EmergencyWithdrawal, KeeperTipUpdated, MaxAccountingDriftUpdated, NoCooldownModeUpdated,
+};
+use crate::state::{PendingAction, VaultAction, VaultActionType, VaultConfig, VaultState};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        init,
+        payer = authority,
+        space = PendingAction::SIZE,
+        seeds = [VAULT_PENDING_ACTION_SEED, vault_config.next_action_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [VAULT_PENDING_ACTION_SEED, pending_action.id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = proposer,
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+    /// CHECK: proposer of the pending action, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::ActionCanceller.to_seed().as_slice()],
+        bump = action_canceller.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub action_canceller: Box<Account<'info, AccessRole>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [VAULT_PENDING_ACTION_SEED, pending_action.id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = proposer,
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+    /// CHECK: proposer of the pending action, receives its rent back
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+
+    // Only required by the emergency withdrawals, checked against the vault state in the instruction
+    #[account(mut)]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub receiver_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct SetActionDelay<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Records an admin action that can be executed once the delay of its type has passed
+pub fn process_queue_action(ctx: Context<QueueAction>, action: VaultAction) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    if action.requires_config_admin() {
+        require!(
+            ctx.accounts.vault_config.admin == ctx.accounts.authority.key(),
+            VaultError::InvalidVaultAdminAuthority
+        );
+    }
+    action.validate()?;
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    let id = vault_config.next_action_id;
+    let queued_at = Clock::get()?.unix_timestamp as u64;
+    let eta = queued_at
+        .checked_add(vault_config.action_delay(action.action_type()))
+        .ok_or(VaultError::MathOverflow)?;
+    vault_config.next_action_id = id.checked_add(1).ok_or(VaultError::MathOverflow)?;
+
+    ctx.accounts.pending_action.set_inner(PendingAction {
+        is_initialized: true,
+        bump: ctx.bumps.pending_action,
+        id,
+        proposer: ctx.accounts.authority.key(),
+        action: action.clone(),
+        queued_at,
+        eta,
+    });
+
+    emit!(ActionQueued {
+        vault_config: vault_config.key(),
+        pending_action: ctx.accounts.pending_action.key(),
+        id,
+        proposer: ctx.accounts.authority.key(),
+        action,
+        eta,
+    });
+
+    Ok(())
+}
+
+/// Drops a pending action before it is executed and refunds its rent to the proposer
+pub fn process_cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.action_canceller.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::ActionCanceller
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        ctx.accounts.pending_action.is_initialized,
+        VaultError::PendingActionNotInitialized
+    );
+
+    emit!(ActionCancelled {
+        vault_config: ctx.accounts.vault_config.key(),
+        pending_action: ctx.accounts.pending_action.key(),
+        id: ctx.accounts.pending_action.id,
+        canceller: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+/// Applies a pending action whose delay has passed, the account is closed to its proposer
+pub fn process_execute_action<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteAction<'info>>,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    let pending_action = &ctx.accounts.pending_action;
+    require!(
+        pending_action.is_initialized,
+        VaultError::PendingActionNotInitialized
+    );
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(pending_action.is_ready(now), VaultError::ActionNotReady);
+    let id = pending_action.id;
+    let action = pending_action.action.clone();
+    // checked again, the admin may have changed since the action was queued
+    if action.requires_config_admin() {
+        require!(
+            ctx.accounts.vault_config.admin == ctx.accounts.authority.key(),
+            VaultError::InvalidVaultAdminAuthority
+        );
+    }
+
+    match action {
+        VaultAction::AdjustCooldown { cooldown_duration } => {
+            let vault_config = &mut ctx.accounts.vault_config;
+            if vault_config.cooldown_duration != cooldown_duration {
+                vault_config.cooldown_duration = cooldown_duration;
+                emit!(CooldownAdjusted {
+                    vault_config: vault_config.key(),
+                    cooldown_duration,
+                });
+            }
+        }
+        VaultAction::EmergencyWithdrawVaultStakePoolUsdu { receiver, amount } => {
+            let vault_config = &mut ctx.accounts.vault_config;
+            require!(
+                vault_config.total_staked_usdu_supply >= amount,
+                VaultError::InsufficientUsduSupply
+            );
+            vault_config.total_staked_usdu_supply -= amount;
+            let (vault_token_account, token_mint) = (
+                ctx.accounts.vault_state.vault_stake_pool_usdu_token_account,
+                ctx.accounts.vault_config.usdu,
+            );
+            emergency_withdraw(
+                &ctx,
+                vault_token_account,
+                token_mint,
+                receiver,
+                amount,
+                VaultError::InvalidVaultStakePoolUsduTokenAccount,
+                VaultError::InsufficientStakePoolUsdu,
+            )?;
+        }
+        VaultAction::EmergencyWithdrawVaultSiloUsdu { receiver, amount } => {
+            // the silo may hold more than the counter so never underflow
+            let vault_config = &mut ctx.accounts.vault_config;
+            vault_config.total_cooldown_usdu_amount = vault_config
+                .total_cooldown_usdu_amount
+                .saturating_sub(amount);
+            let (vault_token_account, token_mint) = (
+                ctx.accounts.vault_state.vault_silo_usdu_token_account,
+                ctx.accounts.vault_config.usdu,
+            );
+            emergency_withdraw(
+                &ctx,
+                vault_token_account,
+                token_mint,
+                receiver,
+                amount,
+                VaultError::InvalidVaultSiloUsduTokenAccount,
+                VaultError::InsufficientSiloUsdu,
+            )?;
+        }
+        VaultAction::EmergencyWithdrawVaultUsdu { receiver, amount } => {
+            emergency_withdraw(
+                &ctx,
+                ctx.accounts.vault_state.vault_usdu_token_account,
+                ctx.accounts.vault_config.usdu,
+                receiver,
+                amount,
+                VaultError::InvalidVaultUsduTokenAccount,
+                VaultError::InsufficientVaultUsdu,
+            )?;
+        }
+        VaultAction::EmergencyWithdrawVaultSusdu { receiver, amount } => {
+            emergency_withdraw(
+                &ctx,
+                ctx.accounts.vault_state.vault_susdu_token_account,
+                ctx.accounts.vault_config.susdu,
+                receiver,
+                amount,
+                VaultError::InvalidVaultSusduTokenAccount,
+                VaultError::InsufficientVaultSusdu,
+            )?;
+        }
+        VaultAction::ResetTotalCooldownUsduAmount { new_amount } => {
+            ctx.accounts.vault_config.total_cooldown_usdu_amount = new_amount;
+        }
+        VaultAction::SetActionDelay { action_type, delay } => {
+            update_action_delay(&mut ctx.accounts.vault_config, action_type, delay);
+        }
+        VaultAction::SetMaxAccountingDrift {
+            max_accounting_drift,
+        } => {
+            let vault_config = &mut ctx.accounts.vault_config;
+            let old_max_accounting_drift = vault_config.max_accounting_drift;
+            vault_config.max_accounting_drift = max_accounting_drift;
+            emit!(MaxAccountingDriftUpdated {
+                vault_config: vault_config.key(),
+                old_max_accounting_drift,
+                max_accounting_drift,
+            });
+        }
+        VaultAction::SetNoCooldownMode { no_cooldown_mode } => {
+            let vault_config = &mut ctx.accounts.vault_config;
+            if vault_config.no_cooldown_mode != no_cooldown_mode {
+                vault_config.no_cooldown_mode = no_cooldown_mode;
+                emit!(NoCooldownModeUpdated {
+                    vault_config: vault_config.key(),
+                    no_cooldown_mode,
+                });
+            }
+        }
+        VaultAction::SetKeeperTip { keeper_tip_bps } => {
+            let vault_config = &mut ctx.accounts.vault_config;
+            vault_config.keeper_tip_bps = keeper_tip_bps;
+            emit!(KeeperTipUpdated {
+                vault_config: vault_config.key(),
+                keeper_tip_bps,
+            });
+        }
+    }
+
+    emit!(ActionExecuted {
+        vault_config: ctx.accounts.vault_config.key(),
+        pending_action: ctx.accounts.pending_action.key(),
+        id,
+        executor: ctx.accounts.authority.key(),
+        action: ctx.accounts.pending_action.action.clone(),
+    });
+
+    Ok(())
+}
+
+/// Raises the delay of an action type at once, lowering it has to be queued as `VaultAction::SetActionDelay`
+pub fn process_set_action_delay(
+    ctx: Context<SetActionDelay>,
+    action_type: VaultActionType,
+    delay: u64,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(delay <= MAX_ACTION_DELAY, VaultError::ActionDelayTooLong);
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    // a shorter delay could let the next action land before stakers can react
+    require!(
+        delay >= vault_config.action_delay(action_type),
+        VaultError::ActionDelayDecreaseNotQueued
+    );
+    update_action_delay(vault_config, action_type, delay);
+
+    Ok(())
+}
+
+fn update_action_delay(
+    vault_config: &mut Account<VaultConfig>,
+    action_type: VaultActionType,
+    delay: u64,
+) {
+    let old_delay = vault_config.action_delay(action_type);
+    vault_config.action_delays[action_type as usize] = delay;
+    emit!(ActionDelayUpdated {
+        vault_config: vault_config.key(),
+        action_type,
+        old_delay,
+        delay,
+    });
+}
+
+/// Moves `amount` from a vault token account to the token account of `receiver`, signed by the vault config
+fn emergency_withdraw<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ExecuteAction<'info>>,
+    expected_vault_token_account: Pubkey,
+    expected_token_mint: Pubkey,
+    receiver: Pubkey,
+    amount: u64,
+    invalid_vault_token_account: VaultError,
+    insufficient_balance: VaultError,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (
+        Some(vault_token_account),
+        Some(receiver_token_account),
+        Some(token_mint),
+        Some(token_program),
+    ) = (
+        accounts.vault_token_account.as_ref(),
+        accounts.receiver_token_account.as_ref(),
+        accounts.token_mint.as_ref(),
+        accounts.token_program.as_ref(),
+    )
+    else {
+        return err!(VaultError::MissingActionAccount);
+    };
+    if vault_token_account.key() != expected_vault_token_account {
+        return Err(invalid_vault_token_account.into());
+    }
+    require!(
+        token_mint.key() == expected_token_mint,
+        VaultError::InvalidActionTokenMint
+    );
+    require!(
+        receiver_token_account.owner == receiver
+            && receiver_token_account.mint == expected_token_mint,
+        VaultError::InvalidActionReceiverTokenAccount
+    );
+    if vault_token_account.amount < amount {
+        return Err(insufficient_balance.into());
+    }
+
+    let config_bump = &[accounts.vault_config.bump];
+    let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
+    invoke_transfer_checked(
+        token_program.key,
+        vault_token_account.to_account_info(),
+        token_mint.to_account_info(),
+        receiver_token_account.to_account_info(),
+        accounts.vault_config.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        token_mint.decimals,
+        config_seeds,
+    )?;
+
+    emit!(EmergencyWithdrawal {
+        vault_config: accounts.vault_config.key(),
+        admin: accounts.authority.key(),
+        token_mint: token_mint.key(),
+        amount,
+        destination: receiver,
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::VAULT_CONFIG_SEED;
+use crate::error::VaultError;
+use crate::events::VestingPeriodUpdated;
+use crate::state::{VaultConfig, MAX_VESTING_PERIOD, MIN_VESTING_PERIOD};
+use crate::utils::has_role_or_admin;
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::{AccessRegistry, AccessRole, Role};
+
+#[derive(Accounts)]
+pub struct SetVestingPeriod<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), authority.key().as_ref(), Role::VaultAdmin.to_seed().as_slice()],
+        bump = vault_admin.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub vault_admin: Box<Account<'info, AccessRole>>,
+}
+
+/// Sets the period over which distributed rewards vest, only once the previous distribution has fully vested
+pub fn process_set_vesting_period(
+    ctx: Context<SetVestingPeriod>,
+    vesting_period: u64,
+) -> Result<()> {
+    require!(
+        has_role_or_admin(
+            &ctx.accounts.vault_config,
+            &ctx.accounts.access_registry,
+            &ctx.accounts.vault_admin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            Role::VaultAdmin
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        vesting_period >= MIN_VESTING_PERIOD,
+        VaultError::VestingPeriodTooShort
+    );
+    require!(
+        vesting_period <= MAX_VESTING_PERIOD,
+        VaultError::VestingPeriodTooLong
+    );
+
+    let vault_config = &mut ctx.accounts.vault_config;
+    // changing the period mid-schedule would make the unvested amount jump
+    require!(
+        vault_config.get_unvested_amount() == 0,
+        VaultError::StillVesting
+    );
+
+    let old_vesting_period = vault_config.vesting_period;
+    vault_config.vesting_period = vesting_period;
+
+    emit!(VestingPeriodUpdated {
+        vault_config: vault_config.key(),
+        old_vesting_period,
+        vesting_period,
+    });
+
+    Ok(())
+}
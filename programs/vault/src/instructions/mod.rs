@@ -1,7 +1,9 @@
 mod admin;
 mod susdu;
 mod usdu;
+mod view;
 
 pub use admin::*;
 pub use susdu::*;
 pub use usdu::*;
+pub use view::*;
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{transfer_checked, TransferChecked};
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+use guardian::utils::has_role;
+
+use crate::constants::{
+    VAULT_CONFIG_SEED, VAULT_COOLDOWN_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::events::CooldownCancelled;
+use crate::state::{Cooldown, VaultConfig, VaultState, PAUSE_STAKE};
+
+use susdu::cpi::{accounts::MintSusdu, mint_susdu};
+use susdu::program::Susdu;
+use susdu::state::SusduConfig;
+use susdu::SUSDU_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct CancelCooldown<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = susdu_token,
+        associated_token::authority = caller,
+        associated_token::token_program = token_program,
+    )]
+    pub caller_susdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: receiver the cooldown was opened for, only used in the cooldown seeds
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown.index.to_le_bytes().as_ref()],
+        bump = cooldown.bump,
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_silo_usdu_token_account_bump,
+        token::mint = usdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_silo_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_stake_pool_usdu_token_account_bump,
+        token::mint = usdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), vault_config.key().as_ref(), Role::SusduMinter.to_seed().as_slice()],
+        bump = susdu_minter.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub susdu_minter: Box<Account<'info, AccessRole>>,
+    #[account(
+        mut,
+        seeds = [SUSDU_CONFIG_SEED],
+        bump = susdu_config.bump,
+        seeds::program = susdu::id(),
+    )]
+    pub susdu_config: Box<Account<'info, SusduConfig>>,
+
+    #[account(mut)]
+    pub susdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub susdu_program: Program<'info, Susdu>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves `usdu_amount` of a cooldown ticket back to the stake pool and mints SUSDU for it at the current rate
+pub fn process_cancel_cooldown(
+    ctx: Context<CancelCooldown>,
+    usdu_amount: u64,
+    min_susdu_out: u64,
+) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_STAKE)?;
+    require!(
+        !ctx.accounts.vault_config.no_cooldown_mode,
+        VaultError::CooldownDisabled
+    );
+    require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+    // 1. check access role
+    require!(
+        has_role(
+            &ctx.accounts.access_registry,
+            &ctx.accounts.susdu_minter.to_account_info(),
+            &ctx.accounts.vault_config.to_account_info(),
+            Role::SusduMinter,
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    // 2. check vault token accounts
+    require!(
+        ctx.accounts.vault_silo_usdu_token_account.key()
+            == ctx.accounts.vault_state.vault_silo_usdu_token_account,
+        VaultError::InvalidVaultSiloUsduTokenAccount
+    );
+    require!(
+        ctx.accounts.vault_stake_pool_usdu_token_account.key()
+            == ctx.accounts.vault_state.vault_stake_pool_usdu_token_account,
+        VaultError::InvalidVaultStakePoolUsduTokenAccount
+    );
+    // 3. check cooldown
+    let cooldown = &mut ctx.accounts.cooldown;
+    require!(cooldown.is_initialized, VaultError::CooldownNotInitialized);
+    require!(
+        cooldown.owner == ctx.accounts.caller.key(),
+        VaultError::InvalidCooldownOwner
+    );
+    require!(
+        usdu_amount <= cooldown.underlying_token_amount,
+        VaultError::InsufficientCooldownAmount
+    );
+    require!(
+        ctx.accounts.vault_silo_usdu_token_account.amount >= usdu_amount,
+        VaultError::InsufficientUsduInSilo
+    );
+
+    // 4. calculate susdu amount at the current rate, before the totals change
+    let vault_config = &mut ctx.accounts.vault_config;
+    let susdu_amount =
+        vault_config.preview_deposit(usdu_amount, ctx.accounts.susdu_config.total_supply);
+    require!(
+        susdu_amount > 0,
+        VaultError::InvalidPreviewDepositSusduAmount
+    );
+    require!(susdu_amount >= min_susdu_out, VaultError::SlippageExceeded);
+
+    // 5. update cooldown and vault totals
+    cooldown.underlying_token_amount -= usdu_amount;
+    vault_config.total_cooldown_usdu_amount = vault_config
+        .total_cooldown_usdu_amount
+        .checked_sub(usdu_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    vault_config.total_staked_usdu_supply = vault_config
+        .total_staked_usdu_supply
+        .checked_add(usdu_amount)
+        .ok_or(VaultError::MathOverflow)?;
+
+    // 6. transfer usdu from vault_silo_usdu_token_account to vault_stake_pool_usdu_token_account
+    let config_bump = &[vault_config.bump];
+    let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_silo_usdu_token_account.to_account_info(),
+                to: ctx
+                    .accounts
+                    .vault_stake_pool_usdu_token_account
+                    .to_account_info(),
+                authority: vault_config.to_account_info(),
+                mint: ctx.accounts.usdu_token.to_account_info(),
+            },
+            config_seeds,
+        ),
+        usdu_amount,
+        ctx.accounts.usdu_token.decimals,
+    )?;
+
+    // 7. mint susdu to caller_susdu_token_account
+    mint_susdu(
+        CpiContext::new_with_signer(
+            ctx.accounts.susdu_program.to_account_info(),
+            MintSusdu {
+                authority: vault_config.to_account_info(),
+                access_registry: ctx.accounts.access_registry.to_account_info(),
+                access_role: ctx.accounts.susdu_minter.to_account_info(),
+                susdu_config: ctx.accounts.susdu_config.to_account_info(),
+                susdu_token: ctx.accounts.susdu_token.to_account_info(),
+                receiver: ctx.accounts.caller.to_account_info(),
+                receiver_token_account: ctx.accounts.caller_susdu_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            },
+            config_seeds,
+        ),
+        susdu_amount,
+    )?;
+
+    emit!(CooldownCancelled {
+        vault_config: vault_config.key(),
+        cooldown: ctx.accounts.cooldown.key(),
+        caller: ctx.accounts.caller.key(),
+        usdu_amount,
+        susdu_amount,
+        remaining_usdu_amount: ctx.accounts.cooldown.underlying_token_amount,
+    });
+
+    // 8. close the empty cooldown account and refund rent to the caller who paid for it
+    if ctx.accounts.cooldown.underlying_token_amount == 0 {
+        ctx.accounts
+            .cooldown
+            .close(ctx.accounts.caller.to_account_info())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{VAULT_CONFIG_SEED, VAULT_COOLDOWN_SEED};
+use crate::error::VaultError;
+use crate::events::CooldownClosed;
+use crate::state::{Cooldown, VaultConfig};
+
+#[derive(Accounts)]
+pub struct CloseCooldown<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    /// CHECK: receiver the cooldown was opened for, only used in the cooldown seeds
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = caller,
+        seeds = [VAULT_COOLDOWN_SEED, cooldown.underlying_token_mint.as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown.index.to_le_bytes().as_ref()],
+        bump = cooldown.bump,
+        constraint = cooldown.owner == caller.key() @ VaultError::InvalidCooldownOwner,
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+}
+
+/// Closes an empty cooldown ticket and refunds its rent to the caller who paid for it
+pub fn process_close_cooldown(ctx: Context<CloseCooldown>) -> Result<()> {
+    require!(
+        ctx.accounts.cooldown.underlying_token_amount == 0,
+        VaultError::CooldownNotEmpty
+    );
+
+    emit!(CooldownClosed {
+        vault_config: ctx.accounts.vault_config.key(),
+        cooldown: ctx.accounts.cooldown.key(),
+        caller: ctx.accounts.caller.key(),
+    });
+    Ok(())
+}
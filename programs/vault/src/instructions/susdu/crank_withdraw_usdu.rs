@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{transfer_checked, TransferChecked};
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, VAULT_CONFIG_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::events::UsduWithdrawnByKeeper;
+use crate::state::{Cooldown, VaultConfig, VaultState, PAUSE_WITHDRAW};
+
+/// Remaining accounts per cooldown: [cooldown, underlying token account, cooldown owner]
+pub const CRANK_ACCOUNTS_PER_COOLDOWN: usize = 3;
+
+#[derive(Accounts)]
+pub struct CrankWithdrawUsdu<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = usdu_token,
+        associated_token::authority = keeper,
+        associated_token::token_program = token_program,
+    )]
+    pub keeper_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_silo_usdu_token_account_bump,
+        token::mint = usdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_silo_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out a batch of matured cooldowns to their recorded token accounts, minus the keeper tip,
+/// and closes each ticket to its owner
+pub fn process_crank_withdraw_usdu<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankWithdrawUsdu<'info>>,
+) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_WITHDRAW)?;
+    // 1. check vault silo usdu token account
+    require!(
+        ctx.accounts.vault_state.vault_silo_usdu_token_account.key()
+            == ctx.accounts.vault_silo_usdu_token_account.key(),
+        VaultError::InvalidVaultSiloUsduTokenAccount
+    );
+    // 2. check remaining accounts
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty()
+            && remaining_accounts.len() % CRANK_ACCOUNTS_PER_COOLDOWN == 0,
+        VaultError::InvalidRemainingAccounts
+    );
+
+    let config_bump = &[ctx.accounts.vault_config.bump];
+    let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    for accounts in remaining_accounts.chunks(CRANK_ACCOUNTS_PER_COOLDOWN) {
+        let (cooldown_info, receiver_usdu_token_account, owner) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        // 3. check cooldown is matured and matches the passed accounts
+        let cooldown = Account::<Cooldown>::try_from(cooldown_info)?;
+        require!(cooldown.is_initialized, VaultError::CooldownNotInitialized);
+        require!(
+            cooldown.underlying_token_mint == ctx.accounts.usdu_token.key(),
+            VaultError::InvalidCooldownUnderlyingTokenMint
+        );
+        require!(
+            !cooldown.is_cooldown_active() || ctx.accounts.vault_config.no_cooldown_mode,
+            VaultError::CooldownActive
+        );
+        require!(
+            cooldown.underlying_token_account == receiver_usdu_token_account.key(),
+            VaultError::InvalidCooldownUnderlyingTokenAccount
+        );
+        require!(
+            cooldown.owner == owner.key(),
+            VaultError::InvalidCooldownOwner
+        );
+
+        // 4. split the keeper tip from the payout
+        let usdu_amount = cooldown.underlying_token_amount;
+        require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+        let keeper_tip = (usdu_amount as u128)
+            .checked_mul(ctx.accounts.vault_config.keeper_tip_bps as u128)
+            .ok_or(VaultError::MathOverflow)?
+            .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+            .ok_or(VaultError::MathOverflow)? as u64;
+        let receiver_amount = usdu_amount - keeper_tip;
+
+        ctx.accounts.vault_silo_usdu_token_account.reload()?;
+        require!(
+            ctx.accounts.vault_silo_usdu_token_account.amount >= usdu_amount,
+            VaultError::InsufficientUsduInSilo
+        );
+
+        let vault_config = &mut ctx.accounts.vault_config;
+        vault_config.total_cooldown_usdu_amount = vault_config
+            .total_cooldown_usdu_amount
+            .checked_sub(usdu_amount)
+            .ok_or(VaultError::MathOverflow)?;
+
+        // 5. transfer usdu to the recorded token account and the tip to the keeper
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_silo_usdu_token_account.to_account_info(),
+                    to: receiver_usdu_token_account.clone(),
+                    authority: vault_config.to_account_info(),
+                    mint: ctx.accounts.usdu_token.to_account_info(),
+                },
+                config_seeds,
+            ),
+            receiver_amount,
+            ctx.accounts.usdu_token.decimals,
+        )?;
+        if keeper_tip > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.vault_silo_usdu_token_account.to_account_info(),
+                        to: ctx.accounts.keeper_usdu_token_account.to_account_info(),
+                        authority: vault_config.to_account_info(),
+                        mint: ctx.accounts.usdu_token.to_account_info(),
+                    },
+                    config_seeds,
+                ),
+                keeper_tip,
+                ctx.accounts.usdu_token.decimals,
+            )?;
+        }
+
+        // 6. close the cooldown account and refund rent to its owner
+        cooldown.close(owner.clone())?;
+
+        emit!(UsduWithdrawnByKeeper {
+            vault_config: vault_config.key(),
+            cooldown: cooldown_info.key(),
+            keeper: ctx.accounts.keeper.key(),
+            owner: owner.key(),
+            usdu_amount: receiver_amount,
+            keeper_tip,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
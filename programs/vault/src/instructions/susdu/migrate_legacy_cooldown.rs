@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::{VAULT_CONFIG_SEED, VAULT_COOLDOWN_COUNTER_SEED, VAULT_COOLDOWN_SEED};
+use crate::error::VaultError;
+use crate::events::LegacyCooldownMigrated;
+use crate::state::{Cooldown, CooldownCounter, LegacyCooldown, VaultConfig};
+
+#[derive(Accounts)]
+pub struct MigrateLegacyCooldown<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    /// CHECK: receiver the legacy cooldown was opened for, only used in the cooldown seeds
+    pub receiver: UncheckedAccount<'info>,
+    /// CHECK: cooldown opened before tickets were indexed, deserialized in the instruction
+    #[account(
+        mut,
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub legacy_cooldown: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = CooldownCounter::SIZE,
+        seeds = [VAULT_COOLDOWN_COUNTER_SEED, caller.key().as_ref()],
+        bump,
+    )]
+    pub cooldown_counter: Box<Account<'info, CooldownCounter>>,
+    #[account(
+        init,
+        payer = caller,
+        space = Cooldown::SIZE,
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown_counter.next_index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves a cooldown opened before tickets were indexed to a new ticket of the caller,
+/// after which it is withdrawn, cancelled or closed like any other ticket
+pub fn process_migrate_legacy_cooldown(ctx: Context<MigrateLegacyCooldown>) -> Result<()> {
+    // 1. read the legacy cooldown
+    let legacy_cooldown_info = ctx.accounts.legacy_cooldown.to_account_info();
+    require!(
+        legacy_cooldown_info.data_len() < Cooldown::SIZE,
+        VaultError::AccountAlreadyMigrated
+    );
+    let legacy = {
+        let data = legacy_cooldown_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Cooldown::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyCooldown::deserialize(&mut &data[8..])?
+    };
+    require!(legacy.is_initialized, VaultError::CooldownNotInitialized);
+    require!(
+        legacy.owner == ctx.accounts.caller.key(),
+        VaultError::InvalidCooldownOwner
+    );
+    require!(
+        legacy.underlying_token_mint == ctx.accounts.usdu_token.key(),
+        VaultError::InvalidCooldownUnderlyingTokenMint
+    );
+
+    // 2. open the indexed ticket with the same claim
+    let cooldown_counter = &mut ctx.accounts.cooldown_counter;
+    if !cooldown_counter.is_initialized {
+        cooldown_counter.is_initialized = true;
+        cooldown_counter.bump = ctx.bumps.cooldown_counter;
+        cooldown_counter.owner = ctx.accounts.caller.key();
+    }
+    let index = cooldown_counter.next_index;
+    ctx.accounts
+        .cooldown
+        .set_inner(Cooldown::from_legacy(legacy, index, ctx.bumps.cooldown));
+    cooldown_counter.next_index = cooldown_counter
+        .next_index
+        .checked_add(1)
+        .ok_or(VaultError::MathOverflow)?;
+
+    // 3. close the legacy cooldown and refund its rent to the caller
+    let caller_info = ctx.accounts.caller.to_account_info();
+    let lamports = legacy_cooldown_info.lamports();
+    **caller_info.try_borrow_mut_lamports()? += lamports;
+    **legacy_cooldown_info.try_borrow_mut_lamports()? = 0;
+    legacy_cooldown_info.assign(&System::id());
+    legacy_cooldown_info.realloc(0, false)?;
+
+    emit!(LegacyCooldownMigrated {
+        vault_config: ctx.accounts.vault_config.key(),
+        legacy_cooldown: ctx.accounts.legacy_cooldown.key(),
+        cooldown: ctx.accounts.cooldown.key(),
+        owner: ctx.accounts.caller.key(),
+        index,
+    });
+    Ok(())
+}
@@ -1,7 +1,19 @@
+mod cancel_cooldown;
+mod close_cooldown;
+mod crank_withdraw_usdu;
+mod migrate_legacy_cooldown;
+mod set_cooldown_receiver;
 mod stake_usdu_mint_susdu;
 mod unstake_susdu;
+mod unstake_susdu_direct;
 mod withdraw_usdu;
 
+pub use cancel_cooldown::*;
+pub use close_cooldown::*;
+pub use crank_withdraw_usdu::*;
+pub use migrate_legacy_cooldown::*;
+pub use set_cooldown_receiver::*;
 pub use stake_usdu_mint_susdu::*;
 pub use unstake_susdu::*;
+pub use unstake_susdu_direct::*;
 pub use withdraw_usdu::*;
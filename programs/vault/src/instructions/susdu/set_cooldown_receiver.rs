@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use blacklist_hook::constants::BLACKLIST_ENTRY_SEED;
+use blacklist_hook::utils::is_in_blacklist;
+
+use crate::constants::VAULT_COOLDOWN_SEED;
+use crate::error::VaultError;
+use crate::events::CooldownReceiverUpdated;
+use crate::state::Cooldown;
+
+#[derive(Accounts)]
+pub struct SetCooldownReceiver<'info> {
+    pub caller: Signer<'info>,
+    /// CHECK: receiver the cooldown was opened for, only used in the cooldown seeds
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown.index.to_le_bytes().as_ref()],
+        bump = cooldown.bump,
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+    /// CHECK: checked against the blacklist in the instruction
+    pub new_receiver: UncheckedAccount<'info>,
+    #[account(
+        associated_token::mint = usdu_token,
+        associated_token::authority = new_receiver,
+        associated_token::token_program = token_program,
+    )]
+    pub new_receiver_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: blacklist entry of the new receiver, may be uninitialized
+    #[account(
+        seeds = [BLACKLIST_ENTRY_SEED.as_bytes(), new_receiver.key().as_ref()],
+        bump,
+        seeds::program = blacklist_hook::id(),
+    )]
+    pub new_receiver_blacklist_entry: UncheckedAccount<'info>,
+
+    #[account(address = cooldown.underlying_token_mint @ VaultError::InvalidCooldownUnderlyingTokenMint)]
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Re-points a pending cooldown ticket to the USDU token account of a new receiver
+pub fn process_set_cooldown_receiver(ctx: Context<SetCooldownReceiver>) -> Result<()> {
+    // 1. check cooldown
+    let cooldown = &mut ctx.accounts.cooldown;
+    require!(cooldown.is_initialized, VaultError::CooldownNotInitialized);
+    require!(
+        cooldown.owner == ctx.accounts.caller.key(),
+        VaultError::InvalidCooldownOwner
+    );
+    require!(
+        cooldown.underlying_token_amount > 0,
+        VaultError::AmountMustBeGreaterThanZero
+    );
+
+    // 2. new receiver must not be in blacklist
+    require!(
+        !is_in_blacklist(
+            &ctx.accounts.new_receiver_blacklist_entry.to_account_info(),
+            &ctx.accounts.new_receiver.key()
+        )?,
+        VaultError::BlacklistAccount
+    );
+
+    // 3. update the payout account, the cooldown address keeps the original receiver in its seeds
+    let old_underlying_token_account = cooldown.underlying_token_account;
+    cooldown.underlying_token_account = ctx.accounts.new_receiver_usdu_token_account.key();
+
+    emit!(CooldownReceiverUpdated {
+        cooldown: cooldown.key(),
+        owner: cooldown.owner,
+        old_underlying_token_account,
+        new_underlying_token_account: cooldown.underlying_token_account,
+    });
+    Ok(())
+}
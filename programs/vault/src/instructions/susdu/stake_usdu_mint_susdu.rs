@@ -4,10 +4,12 @@ use anchor_spl::token_2022::{transfer_checked, TransferChecked};
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::constants::{
-    VAULT_CONFIG_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+    VAULT_CONFIG_SEED, VAULT_DEAD_SHARES_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STATE_SEED,
 };
 use crate::error::VaultError;
-use crate::state::{VaultConfig, VaultState};
+use crate::events::InitialDeadSharesMinted;
+use crate::state::{VaultConfig, VaultState, PAUSE_STAKE};
 
 use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
 use guardian::state::{AccessRegistry, AccessRole, Role};
@@ -38,6 +40,20 @@ pub struct StakeUsduMintSusdu<'info> {
         associated_token::token_program = token_program,
     )]
     pub caller_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program derived address nothing signs for, holds the dead shares of the initial deposit
+    #[account(
+        seeds = [VAULT_DEAD_SHARES_SEED],
+        bump,
+    )]
+    pub dead_shares_owner: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = susdu_token,
+        associated_token::authority = dead_shares_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub dead_shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         seeds = [ACCESS_REGISTRY_SEED],
         seeds::program = guardian::id(),
@@ -92,7 +108,65 @@ pub struct StakeUsduMintSusdu<'info> {
 pub fn process_stake_usdu_mint_susdu(
     ctx: Context<StakeUsduMintSusdu>,
     usdu_amount: u64,
+    min_susdu_out: u64,
 ) -> Result<()> {
+    require!(usdu_amount > 0, VaultError::InvalidStakeUsduAmount);
+    let vault_config = &ctx.accounts.vault_config;
+    let max_assets = vault_config.max_deposit();
+    require!(usdu_amount <= max_assets, VaultError::MaxDepositExceeded);
+
+    // calculate susdu amount(shares amount) to transfer to receiver_susdu_token_account
+    let total_susdu_supply = ctx.accounts.susdu_config.total_supply;
+    let susdu_amount = if !vault_config.has_initial_deposit {
+        // Initial deposit, the dead shares are carved out of the depositor's shares
+        vault_config
+            .preview_deposit(usdu_amount, total_susdu_supply)
+            .saturating_sub(vault_config.initial_dead_shares())
+    } else {
+        vault_config.preview_deposit(usdu_amount, total_susdu_supply)
+    };
+    require!(
+        susdu_amount > 0,
+        VaultError::InvalidPreviewDepositSusduAmount
+    );
+    require!(susdu_amount >= min_susdu_out, VaultError::SlippageExceeded);
+
+    execute_stake_usdu_mint_susdu(ctx, usdu_amount, susdu_amount)
+}
+
+/// Mints exactly `susdu_amount` shares, pulling the USDU they are worth rounded up
+pub fn process_mint_susdu_exact(
+    ctx: Context<StakeUsduMintSusdu>,
+    susdu_amount: u64,
+    max_usdu_in: u64,
+) -> Result<()> {
+    require!(susdu_amount > 0, VaultError::InvalidMintSusduAmount);
+    let vault_config = &ctx.accounts.vault_config;
+    let max_shares = vault_config.max_mint();
+    require!(susdu_amount <= max_shares, VaultError::MaxMintExceeded);
+
+    let total_susdu_supply = ctx.accounts.susdu_config.total_supply;
+    let usdu_amount = if !vault_config.has_initial_deposit {
+        // Initial deposit, the depositor also pays for the dead shares
+        let total_minted_shares = susdu_amount
+            .checked_add(vault_config.initial_dead_shares())
+            .ok_or(VaultError::MathOverflow)?;
+        vault_config.preview_mint(total_minted_shares, total_susdu_supply)
+    } else {
+        vault_config.preview_mint(susdu_amount, total_susdu_supply)
+    };
+    require!(usdu_amount > 0, VaultError::InvalidStakeUsduAmount);
+    require!(usdu_amount <= max_usdu_in, VaultError::SlippageExceeded);
+
+    execute_stake_usdu_mint_susdu(ctx, usdu_amount, susdu_amount)
+}
+
+fn execute_stake_usdu_mint_susdu(
+    ctx: Context<StakeUsduMintSusdu>,
+    usdu_amount: u64,
+    susdu_amount: u64,
+) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_STAKE)?;
     // 1. check access role
     require!(
         has_role(
@@ -110,37 +184,20 @@ pub fn process_stake_usdu_mint_susdu(
             == ctx.accounts.vault_state.vault_stake_pool_usdu_token_account,
         VaultError::InvalidVaultStakePoolUsduTokenAccount
     );
-    // 3. check usdu amount and initial deposit
-    require!(usdu_amount > 0, VaultError::InvalidStakeUsduAmount);
+    // 3. check initial deposit
     let vault_config = &mut ctx.accounts.vault_config;
     vault_config.check_initial_deposit(usdu_amount)?;
 
-    // 4. check max deposit
-    let max_assets = vault_config.max_deposit();
-    require!(usdu_amount <= max_assets, VaultError::MaxDepositExceeded);
-
+    // 4. check caller balance
     require!(
         ctx.accounts.caller_usdu_token_account.amount >= usdu_amount,
         VaultError::InsufficientUsduBalance
     );
 
-    // 5. calculate susdu amount(shares amount) to transfer to receiver_susdu_token_account
-    let total_susdu_supply = ctx.accounts.susdu_config.total_supply;
-    let susdu_amount = if !vault_config.has_initial_deposit {
-        // Initial deposit, set initial shares amount
-        usdu_amount
-    } else {
-        vault_config.preview_deposit(usdu_amount, total_susdu_supply)
-    };
-    require!(
-        susdu_amount > 0,
-        VaultError::InvalidPreviewDepositSusduAmount
-    );
-
-    // 6. update total_staked_usdu_supply
-    vault_config.total_staked_usdu_supply = vault_config.total_staked_usdu_supply + usdu_amount;
+    // 5. update total_staked_usdu_supply
+    vault_config.total_staked_usdu_supply += usdu_amount;
 
-    // 7. transfer usdu from caller to vault_pool_usdu_token_account
+    // 6. transfer usdu from caller to vault_pool_usdu_token_account
     transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -158,7 +215,7 @@ pub fn process_stake_usdu_mint_susdu(
         ctx.accounts.usdu_token.decimals,
     )?;
 
-    // 8. mint susdu to receiver_susdu_token_account
+    // 7. mint susdu to receiver_susdu_token_account
     let config_bump = &[vault_config.bump];
     let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
     mint_susdu(
@@ -181,17 +238,48 @@ pub fn process_stake_usdu_mint_susdu(
         susdu_amount,
     )?;
 
-    // 9. update initial deposit status
+    // 8. mint the dead shares to the burn account and update initial deposit status
     if !vault_config.has_initial_deposit {
+        let dead_shares = vault_config.initial_dead_shares();
+        mint_susdu(
+            CpiContext::new_with_signer(
+                ctx.accounts.susdu_program.to_account_info(),
+                MintSusdu {
+                    authority: vault_config.to_account_info(),
+                    access_registry: ctx.accounts.access_registry.to_account_info(),
+                    access_role: ctx.accounts.susdu_minter.to_account_info(),
+                    susdu_config: ctx.accounts.susdu_config.to_account_info(),
+                    susdu_token: ctx.accounts.susdu_token.to_account_info(),
+                    receiver: ctx.accounts.dead_shares_owner.to_account_info(),
+                    receiver_token_account: ctx
+                        .accounts
+                        .dead_shares_token_account
+                        .to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    associated_token_program: ctx
+                        .accounts
+                        .associated_token_program
+                        .to_account_info(),
+                },
+                config_seeds,
+            ),
+            dead_shares,
+        )?;
         vault_config.has_initial_deposit = true;
+        emit!(InitialDeadSharesMinted {
+            vault_config: vault_config.key(),
+            dead_shares_token_account: ctx.accounts.dead_shares_token_account.key(),
+            dead_shares,
+        });
     }
 
-    // 10. reload susdu_config to get updated total_supply
+    // 9. reload susdu_config to get updated total_supply
     let susdu_config = &ctx.accounts.susdu_config.to_account_info();
     let susdu_config_data = susdu_config.try_borrow_data()?;
     let updated_total_supply = SusduConfig::try_deserialize(&mut &susdu_config_data[..])?;
 
-    // 11. check min shares with updated total_supply, reload susdu_config first
+    // 10. check min shares with updated total_supply, reload susdu_config first
     ctx.accounts.susdu_config.reload()?;
     vault_config.check_min_shares(updated_total_supply.total_supply)?;
     Ok(())
@@ -9,11 +9,12 @@ use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
 use guardian::state::{AccessRegistry, AccessRole, Role};
 
 use crate::constants::{
-    VAULT_CONFIG_SEED, VAULT_COOLDOWN_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
-    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED, VAULT_SUSDU_TOKEN_ACCOUNT_SEED,
+    VAULT_CONFIG_SEED, VAULT_COOLDOWN_COUNTER_SEED, VAULT_COOLDOWN_SEED,
+    VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+    VAULT_SUSDU_TOKEN_ACCOUNT_SEED,
 };
 use crate::error::VaultError;
-use crate::state::{Cooldown, VaultConfig, VaultState};
+use crate::state::{Cooldown, CooldownCounter, VaultConfig, VaultState, PAUSE_UNSTAKE};
 
 use susdu::cpi::{accounts::RedeemSusdu, redeem_susdu};
 use susdu::program::Susdu;
@@ -72,8 +73,17 @@ pub struct UnstakeSusdu<'info> {
     #[account(
         init_if_needed,
         payer = caller,
+        space = CooldownCounter::SIZE,
+        seeds = [VAULT_COOLDOWN_COUNTER_SEED, caller.key().as_ref()],
+        bump,
+    )]
+    pub cooldown_counter: Box<Account<'info, CooldownCounter>>,
+    /// Every unstake opens a new ticket with its own amount and end time
+    #[account(
+        init,
+        payer = caller,
         space = Cooldown::SIZE,
-        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref()],
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown_counter.next_index.to_le_bytes().as_ref()],
         bump,
     )]
     pub cooldown: Box<Account<'info, Cooldown>>,
@@ -127,7 +137,56 @@ pub struct UnstakeSusdu<'info> {
 pub(crate) fn process_unstake_susdu<'info>(
     ctx: Context<'_, '_, '_, 'info, UnstakeSusdu<'info>>,
     susdu_amount: u64,
+    min_usdu_out: u64,
 ) -> Result<()> {
+    require!(susdu_amount > 0, VaultError::InvalidUnstakeSusduAmount);
+    let vault_config = &ctx.accounts.vault_config;
+    let max_shares = vault_config.max_redeem(ctx.accounts.caller_susdu_token_account.amount);
+    require!(susdu_amount <= max_shares, VaultError::MaxRedeemExceeded);
+
+    let total_susdu_amount = ctx.accounts.susdu_config.total_supply;
+    let usdu_amount = vault_config.preview_redeem(susdu_amount, total_susdu_amount);
+    require!(usdu_amount > 0, VaultError::InvalidPreviewRedeemUsduAmount);
+    require!(usdu_amount >= min_usdu_out, VaultError::SlippageExceeded);
+
+    execute_unstake_susdu(ctx, susdu_amount, usdu_amount)
+}
+
+/// Puts exactly `usdu_amount` into cooldown, burning the SUSDU it is worth rounded up
+pub(crate) fn process_unstake_usdu_exact<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnstakeSusdu<'info>>,
+    usdu_amount: u64,
+    max_susdu_in: u64,
+) -> Result<()> {
+    require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+    let vault_config = &ctx.accounts.vault_config;
+    let total_susdu_amount = ctx.accounts.susdu_config.total_supply;
+    let max_assets = vault_config.max_withdraw(
+        ctx.accounts.caller_susdu_token_account.amount,
+        total_susdu_amount,
+    );
+    require!(usdu_amount <= max_assets, VaultError::MaxWithdrawExceeded);
+
+    let susdu_amount = vault_config.preview_withdraw(usdu_amount, total_susdu_amount);
+    require!(
+        susdu_amount > 0,
+        VaultError::InvalidPreviewWithdrawSusduAmount
+    );
+    require!(susdu_amount <= max_susdu_in, VaultError::SlippageExceeded);
+
+    execute_unstake_susdu(ctx, susdu_amount, usdu_amount)
+}
+
+fn execute_unstake_susdu<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnstakeSusdu<'info>>,
+    susdu_amount: u64,
+    usdu_amount: u64,
+) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_UNSTAKE)?;
+    require!(
+        !ctx.accounts.vault_config.no_cooldown_mode,
+        VaultError::CooldownDisabled
+    );
     // 1. check caller has the role
     require!(
         has_role(
@@ -169,61 +228,52 @@ pub(crate) fn process_unstake_susdu<'info>(
             == ctx.accounts.vault_susdu_token_account.key(),
         VaultError::InvalidVaultSusduTokenAccount
     );
-    // 5. check susdu amount
-    require!(susdu_amount > 0, VaultError::InvalidUnstakeSusduAmount);
-    let total_susdu_amount = ctx.accounts.susdu_config.total_supply;
     let vault_config = &mut ctx.accounts.vault_config;
-    // 6. check caller has enough susdu
+    // 5. check caller has enough susdu
     require!(
         ctx.accounts.caller_susdu_token_account.amount >= susdu_amount,
         VaultError::InsufficientSusdu
     );
-    let usdu_amount = vault_config.preview_redeem(susdu_amount, total_susdu_amount);
-    // 7. check preview redeem usdu amount
-    require!(usdu_amount > 0, VaultError::InvalidPreviewRedeemUsduAmount);
-    // 8. check total usdu supply
+    // 6. check total usdu supply
     require!(
         vault_config.total_staked_usdu_supply >= usdu_amount,
         VaultError::InsufficientUsduSupply
     );
 
-    vault_config.total_staked_usdu_supply = vault_config.total_staked_usdu_supply - usdu_amount;
+    vault_config.total_staked_usdu_supply -= usdu_amount;
 
     vault_config.total_cooldown_usdu_amount = vault_config.total_cooldown_usdu_amount
         .checked_add(usdu_amount)
         .ok_or(VaultError::MathOverflow)?;
 
-    // 9. check cooldown is initialized
-    if !ctx.accounts.cooldown.is_initialized {
-        let cooldown = Cooldown {
-            is_initialized: true,
-            cooldown_end: Clock::get().unwrap().unix_timestamp as u64
-                + vault_config.cooldown_duration,
-            underlying_token_account: ctx
-                .accounts
-                .receiver_usdu_token_account
-                .to_account_info()
-                .key(),
-            underlying_token_mint: ctx.accounts.usdu_token.to_account_info().key(),
-            underlying_token_amount: usdu_amount,
-            owner: ctx.accounts.caller.key(),
-            bump: ctx.bumps.cooldown,
-        };
-        ctx.accounts.cooldown.set_inner(cooldown);
-    } else {
-        require!(
-            ctx.accounts.cooldown.owner == ctx.accounts.caller.key(),
-            VaultError::InvalidCooldownOwner
-        );
-        let cooldown = &mut ctx.accounts.cooldown;
-
-        // Consistent with Ethena protocol, reset cooldown period for each unstake
-        cooldown.cooldown_end =
-            Clock::get().unwrap().unix_timestamp as u64 + vault_config.cooldown_duration;
-        cooldown.underlying_token_amount += usdu_amount;
+    // 7. open a new cooldown ticket
+    let cooldown_counter = &mut ctx.accounts.cooldown_counter;
+    if !cooldown_counter.is_initialized {
+        cooldown_counter.is_initialized = true;
+        cooldown_counter.bump = ctx.bumps.cooldown_counter;
+        cooldown_counter.owner = ctx.accounts.caller.key();
+    }
+    let cooldown = Cooldown {
+        is_initialized: true,
+        cooldown_end: Clock::get()?.unix_timestamp as u64 + vault_config.cooldown_duration,
+        underlying_token_account: ctx
+            .accounts
+            .receiver_usdu_token_account
+            .to_account_info()
+            .key(),
+        underlying_token_mint: ctx.accounts.usdu_token.to_account_info().key(),
+        underlying_token_amount: usdu_amount,
+        owner: ctx.accounts.caller.key(),
+        bump: ctx.bumps.cooldown,
+        index: cooldown_counter.next_index,
     };
+    ctx.accounts.cooldown.set_inner(cooldown);
+    cooldown_counter.next_index = cooldown_counter
+        .next_index
+        .checked_add(1)
+        .ok_or(VaultError::MathOverflow)?;
 
-    // 10. transfer susdu from caller to vault susdu token account
+    // 8. transfer susdu from caller to vault susdu token account
     invoke_transfer_checked(
         ctx.accounts.token_program.key,
         ctx.accounts.caller_susdu_token_account.to_account_info(),
@@ -236,7 +286,7 @@ pub(crate) fn process_unstake_susdu<'info>(
         &[],
     )?;
 
-    // 11. redeem susdu from vault susdu token account
+    // 9. redeem susdu from vault susdu token account
     let config_bump = &[vault_config.bump];
     let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
     redeem_susdu(
@@ -257,7 +307,7 @@ pub(crate) fn process_unstake_susdu<'info>(
         susdu_amount,
     )?;
 
-    // 12. transfer usdu from vault_stake_pool_usdu_token_account to vault_silo_usdu_token_account
+    // 10. transfer usdu from vault_stake_pool_usdu_token_account to vault_silo_usdu_token_account
     transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -276,7 +326,7 @@ pub(crate) fn process_unstake_susdu<'info>(
         ctx.accounts.usdu_token.decimals,
     )?;
 
-    // 13. check min shares, reload susdu_config first
+    // 11. check min shares, reload susdu_config first
     ctx.accounts.susdu_config.reload()?;
     vault_config.check_min_shares(ctx.accounts.susdu_config.total_supply)?;
     Ok(())
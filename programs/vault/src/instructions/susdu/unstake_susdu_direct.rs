@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{
+    spl_token_2022::onchain::invoke_transfer_checked, transfer_checked, TransferChecked,
+};
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
+use guardian::state::{AccessRegistry, AccessRole, Role};
+use guardian::utils::has_role;
+
+use crate::constants::{
+    VAULT_CONFIG_SEED, VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+    VAULT_SUSDU_TOKEN_ACCOUNT_SEED,
+};
+use crate::error::VaultError;
+use crate::events::SusduUnstakedDirect;
+use crate::state::{VaultConfig, VaultState, PAUSE_UNSTAKE};
+
+use susdu::cpi::{accounts::RedeemSusdu, redeem_susdu};
+use susdu::program::Susdu;
+use susdu::state::SusduConfig;
+use susdu::SUSDU_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct UnstakeSusduDirect<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = susdu_token,
+        associated_token::authority = caller,
+        associated_token::token_program = token_program,
+    )]
+    pub caller_susdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: no need to checked
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = usdu_token,
+        associated_token::authority = receiver,
+        associated_token::token_program = token_program,
+    )]
+    pub receiver_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [SUSDU_CONFIG_SEED],
+        bump = susdu_config.bump,
+        seeds::program = susdu::id(),
+    )]
+    pub susdu_config: Box<Account<'info, SusduConfig>>,
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SUSDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_susdu_token_account_bump,
+        token::mint = susdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_susdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [ACCESS_REGISTRY_SEED],
+        seeds::program = guardian::id(),
+        bump = access_registry.bump,
+    )]
+    pub access_registry: Box<Account<'info, AccessRegistry>>,
+    #[account(
+        seeds = [ACCESS_ROLE_SEED, access_registry.key().as_ref(), vault_config.key().as_ref(), Role::SusduRedeemer.to_seed().as_slice()],
+        bump = susdu_redeemer.bump,
+        seeds::program = guardian::id(),
+    )]
+    pub susdu_redeemer: Box<Account<'info, AccessRole>>,
+
+    #[account(mut)]
+    pub susdu_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_stake_pool_usdu_token_account_bump,
+        token::mint = usdu_token,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub susdu_program: Program<'info, Susdu>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burns SUSDU and pays the USDU straight from the stake pool, only while the no-cooldown mode is on
+pub fn process_unstake_susdu_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnstakeSusduDirect<'info>>,
+    susdu_amount: u64,
+    min_usdu_out: u64,
+) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_UNSTAKE)?;
+    require!(
+        ctx.accounts.vault_config.no_cooldown_mode,
+        VaultError::CooldownEnabled
+    );
+    // 1. check caller has the role
+    require!(
+        has_role(
+            &ctx.accounts.access_registry,
+            &ctx.accounts.susdu_redeemer.to_account_info(),
+            &ctx.accounts.vault_config.to_account_info(),
+            Role::SusduRedeemer,
+        )?,
+        VaultError::UnauthorizedRole
+    );
+    require!(
+        ctx.accounts.usdu_token.key() == ctx.accounts.vault_config.usdu,
+        VaultError::InvalidUsduToken
+    );
+    require!(
+        ctx.accounts.susdu_token.key() == ctx.accounts.vault_config.susdu,
+        VaultError::InvalidSusduToken
+    );
+
+    // 2. check susdu amount
+    require!(susdu_amount > 0, VaultError::InvalidUnstakeSusduAmount);
+    let vault_config = &mut ctx.accounts.vault_config;
+    let max_shares = vault_config.max_redeem(ctx.accounts.caller_susdu_token_account.amount);
+    require!(susdu_amount <= max_shares, VaultError::MaxRedeemExceeded);
+
+    // 3. check preview redeem usdu amount
+    let total_susdu_amount = ctx.accounts.susdu_config.total_supply;
+    let usdu_amount = vault_config.preview_redeem(susdu_amount, total_susdu_amount);
+    require!(usdu_amount > 0, VaultError::InvalidPreviewRedeemUsduAmount);
+    require!(usdu_amount >= min_usdu_out, VaultError::SlippageExceeded);
+
+    // 4. check total usdu supply and stake pool balance
+    require!(
+        vault_config.total_staked_usdu_supply >= usdu_amount,
+        VaultError::InsufficientUsduSupply
+    );
+    require!(
+        ctx.accounts.vault_stake_pool_usdu_token_account.amount >= usdu_amount,
+        VaultError::InsufficientStakePoolUsdu
+    );
+    vault_config.total_staked_usdu_supply -= usdu_amount;
+
+    // 5. transfer susdu from caller to vault susdu token account
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.caller_susdu_token_account.to_account_info(),
+        ctx.accounts.susdu_token.to_account_info(),
+        ctx.accounts.vault_susdu_token_account.to_account_info(),
+        ctx.accounts.caller.to_account_info(),
+        ctx.remaining_accounts,
+        susdu_amount,
+        ctx.accounts.susdu_token.decimals,
+        &[],
+    )?;
+
+    // 6. redeem susdu from vault susdu token account
+    let config_bump = &[vault_config.bump];
+    let config_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
+    redeem_susdu(
+        CpiContext::new_with_signer(
+            ctx.accounts.susdu_program.to_account_info(),
+            RedeemSusdu {
+                caller: vault_config.to_account_info(),
+                access_registry: ctx.accounts.access_registry.to_account_info(),
+                access_role: ctx.accounts.susdu_redeemer.to_account_info(),
+                susdu_config: ctx.accounts.susdu_config.to_account_info(),
+                susdu_token: ctx.accounts.susdu_token.to_account_info(),
+                caller_token_account: ctx.accounts.vault_susdu_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            config_seeds,
+        ),
+        susdu_amount,
+    )?;
+
+    // 7. transfer usdu from vault_stake_pool_usdu_token_account to receiver_usdu_token_account
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx
+                    .accounts
+                    .vault_stake_pool_usdu_token_account
+                    .to_account_info(),
+                to: ctx.accounts.receiver_usdu_token_account.to_account_info(),
+                authority: vault_config.to_account_info(),
+                mint: ctx.accounts.usdu_token.to_account_info(),
+            },
+            config_seeds,
+        ),
+        usdu_amount,
+        ctx.accounts.usdu_token.decimals,
+    )?;
+
+    // 8. check min shares, reload susdu_config first
+    ctx.accounts.susdu_config.reload()?;
+    vault_config.check_min_shares(ctx.accounts.susdu_config.total_supply)?;
+
+    emit!(SusduUnstakedDirect {
+        vault_config: vault_config.key(),
+        caller: ctx.accounts.caller.key(),
+        receiver: ctx.accounts.receiver.key(),
+        susdu_amount,
+        usdu_amount,
+    });
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use crate::constants::{
 };
 use crate::error::VaultError;
 use crate::events::UsduWithdrawn;
-use crate::state::{Cooldown, VaultConfig, VaultState};
+use crate::state::{Cooldown, VaultConfig, VaultState, PAUSE_WITHDRAW};
 
 #[derive(Accounts)]
 pub struct WithdrawUsdu<'info> {
@@ -27,11 +27,11 @@ pub struct WithdrawUsdu<'info> {
         bump = vault_state.bump,
     )]
     pub vault_state: Box<Account<'info, VaultState>>,
+    /// May differ from the receiver's ATA once the owner re-points the cooldown
     #[account(
          mut,
-         associated_token::mint = usdu_token,
-         associated_token::authority = receiver,
-         associated_token::token_program = token_program,
+         token::mint = usdu_token,
+         token::token_program = token_program,
      )]
     pub receiver_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
@@ -45,7 +45,7 @@ pub struct WithdrawUsdu<'info> {
     pub vault_silo_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
-        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref()],
+        seeds = [VAULT_COOLDOWN_SEED, usdu_token.key().as_ref(), receiver.key().as_ref(), caller.key().as_ref(), cooldown.index.to_le_bytes().as_ref()],
         bump = cooldown.bump,
     )]
     pub cooldown: Box<Account<'info, Cooldown>>,
@@ -56,7 +56,9 @@ pub struct WithdrawUsdu<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
+/// Withdraws `usdu_amount` of a matured cooldown ticket, closing it to the caller once it is empty
+pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>, usdu_amount: u64) -> Result<()> {
+    ctx.accounts.vault_config.check_not_paused(PAUSE_WITHDRAW)?;
     // 1. check vault slio usdu token account
     require!(
         ctx.accounts.vault_state.vault_silo_usdu_token_account.key()
@@ -68,8 +70,9 @@ pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
         ctx.accounts.cooldown.is_initialized,
         VaultError::CooldownNotInitialized
     );
+    // consistent with Ethena, pending cooldowns are claimable at once in no-cooldown mode
     require!(
-        !ctx.accounts.cooldown.is_cooldown_active(),
+        !ctx.accounts.cooldown.is_cooldown_active() || ctx.accounts.vault_config.no_cooldown_mode,
         VaultError::CooldownActive
     );
     require!(
@@ -83,10 +86,13 @@ pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
         VaultError::InvalidReceiverUsduTokenAccount
     );
     let vault_config = &mut ctx.accounts.vault_config;
-    let usdu_amount = ctx.accounts.cooldown.underlying_token_amount;
 
     // 4. check usdu amount
     require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
+    require!(
+        usdu_amount <= ctx.accounts.cooldown.underlying_token_amount,
+        VaultError::InsufficientCooldownAmount
+    );
 
     // 5. verify vault_silo_usdu_token_account has enough USDU to process withdrawal
     require!(
@@ -99,10 +105,9 @@ pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
         .checked_sub(usdu_amount)
         .ok_or(VaultError::MathOverflow)?;
 
-    // 6. set cooldown account underlying_token_amount to 0 to prevent reentrancy
+    // 6. reduce cooldown account underlying_token_amount before the transfer to prevent reentrancy
     let cooldown = &mut ctx.accounts.cooldown;
-    cooldown.cooldown_end = 0;
-    cooldown.underlying_token_amount = 0;
+    cooldown.underlying_token_amount -= usdu_amount;
 
     // 7. transfer usdu from vault_silo_usdu_token_account to receiver_usdu_token_account
     let config_bump = &[vault_config.bump];
@@ -126,10 +131,18 @@ pub fn process_withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
     emit!(UsduWithdrawn {
         vault_config: vault_config.key(),
         caller: ctx.accounts.caller.key(),
-        receiver: ctx.accounts.receiver.key(),
+        // the payee, which differs from the seed receiver once the cooldown is re-pointed
+        receiver: ctx.accounts.receiver_usdu_token_account.owner,
         usdu_amount,
         timestamp: Clock::get()?.unix_timestamp as u64,
     });
+
+    // 8. close the empty cooldown account and refund rent to the caller who paid for it
+    if ctx.accounts.cooldown.underlying_token_amount == 0 {
+        ctx.accounts
+            .cooldown
+            .close(ctx.accounts.caller.to_account_info())?;
+    }
     
     Ok(())
 }
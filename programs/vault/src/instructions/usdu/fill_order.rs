@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::constants::VAULT_ORDER_NONCE_SEED;
+use crate::error::VaultError;
+use crate::events::{MintOrderFilled, RedeemOrderFilled};
+use crate::state::{check_order_expiry, MintOrder, OrderNonceBitmap, RedeemOrder};
+use crate::utils::verify_ed25519_instruction;
+
+// glob import, the derived composite accounts need the nested structs' generated modules in scope
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(order: MintOrder)]
+pub struct MintUsduWithOrder<'info> {
+    pub deposit: DepositCollateralMintUsdu<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderNonceBitmap::SIZE,
+        seeds = [VAULT_ORDER_NONCE_SEED, order.benefactor.as_ref(), OrderNonceBitmap::page_of(order.nonce).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub nonce_bitmap: Box<Account<'info, OrderNonceBitmap>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(order: RedeemOrder)]
+pub struct RedeemUsduWithOrder<'info> {
+    pub redeem: RedeemUsduWithdrawCollateral<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderNonceBitmap::SIZE,
+        seeds = [VAULT_ORDER_NONCE_SEED, order.benefactor.as_ref(), OrderNonceBitmap::page_of(order.nonce).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub nonce_bitmap: Box<Account<'info, OrderNonceBitmap>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Fills a mint order signed off-chain by the benefactor.
+/// The ed25519 program instruction carrying the signature must directly precede this instruction.
+pub fn process_mint_usdu_with_order(
+    ctx: Context<MintUsduWithOrder>,
+    order: MintOrder,
+) -> Result<()> {
+    let deposit = &ctx.accounts.deposit;
+    require!(
+        order.benefactor == deposit.benefactor.key()
+            && order.beneficiary == deposit.beneficiary.key()
+            && order.collateral_mint == deposit.collateral_token.key(),
+        VaultError::OrderAccountsMismatch
+    );
+    check_order_expiry(order.expiry)?;
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions_sysvar,
+        &order.benefactor,
+        &order.message()?,
+    )?;
+    use_order_nonce(
+        &mut ctx.accounts.nonce_bitmap,
+        order.benefactor,
+        order.nonce,
+        ctx.bumps.nonce_bitmap,
+    )?;
+
+    let deposit = &mut ctx.accounts.deposit;
+    execute_deposit_collateral_mint_usdu(deposit, order.collateral_amount, order.usdu_amount)?;
+
+    emit!(MintOrderFilled {
+        benefactor: order.benefactor,
+        beneficiary: order.beneficiary,
+        filler: deposit.authority.key(),
+        nonce: order.nonce,
+    });
+    Ok(())
+}
+
+/// Fills a redeem order signed off-chain by the benefactor, the owner of the USDU.
+/// The ed25519 program instruction carrying the signature must directly precede this instruction.
+pub fn process_redeem_usdu_with_order(
+    ctx: Context<RedeemUsduWithOrder>,
+    order: RedeemOrder,
+) -> Result<()> {
+    let redeem = &ctx.accounts.redeem;
+    // USDU is taken from redeem.beneficiary and collateral is paid to redeem.benefactor
+    require!(
+        order.benefactor == redeem.beneficiary.key()
+            && order.beneficiary == redeem.benefactor.key()
+            && order.collateral_mint == redeem.collateral_token.key(),
+        VaultError::OrderAccountsMismatch
+    );
+    check_order_expiry(order.expiry)?;
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions_sysvar,
+        &order.benefactor,
+        &order.message()?,
+    )?;
+    use_order_nonce(
+        &mut ctx.accounts.nonce_bitmap,
+        order.benefactor,
+        order.nonce,
+        ctx.bumps.nonce_bitmap,
+    )?;
+
+    let redeem = &mut ctx.accounts.redeem;
+    execute_redeem_usdu_withdraw_collateral(redeem, order.collateral_amount, order.usdu_amount)?;
+
+    emit!(RedeemOrderFilled {
+        benefactor: order.benefactor,
+        beneficiary: order.beneficiary,
+        filler: redeem.authority.key(),
+        nonce: order.nonce,
+    });
+    Ok(())
+}
+
+fn use_order_nonce(
+    nonce_bitmap: &mut OrderNonceBitmap,
+    owner: Pubkey,
+    nonce: u64,
+    bump: u8,
+) -> Result<()> {
+    if !nonce_bitmap.is_initialized {
+        nonce_bitmap.is_initialized = true;
+        nonce_bitmap.bump = bump;
+        nonce_bitmap.owner = owner;
+        nonce_bitmap.page = OrderNonceBitmap::page_of(nonce);
+    }
+    nonce_bitmap.use_nonce(nonce)
+}
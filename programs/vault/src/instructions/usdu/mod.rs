@@ -1,5 +1,7 @@
 mod deposit_collateral_mint_usdu;
+mod fill_order;
 mod redeem_usdu_withdraw_collateral;
 
 pub use deposit_collateral_mint_usdu::*;
+pub use fill_order::*;
 pub use redeem_usdu_withdraw_collateral::*;
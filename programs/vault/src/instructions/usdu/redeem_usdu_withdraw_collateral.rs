@@ -4,11 +4,17 @@ use anchor_spl::token_interface::{
     transfer_checked, Mint, Token2022, TokenAccount, TransferChecked,
 };
 
-use crate::constants::{VAULT_CONFIG_SEED, VAULT_STATE_SEED, VAULT_USDU_TOKEN_ACCOUNT_SEED};
+use crate::constants::{
+    VAULT_COLLATERAL_CONFIG_SEED, VAULT_CONFIG_SEED, VAULT_CUSTODIAN_SEED, VAULT_PRICE_FEED_SEED,
+    VAULT_PRIMARY_PARTICIPANT_SEED, VAULT_STATE_SEED, VAULT_USDU_TOKEN_ACCOUNT_SEED,
+};
 use crate::error::VaultError;
 use crate::events::RedeemUsduWithdrawCollateralEvent;
-use crate::state::{VaultConfig, VaultState};
-use crate::utils::{get_transfer_inverse_fee, is_supported_mint};
+use crate::state::{
+    CollateralConfig, Custodian, PriceFeed, PrimaryParticipant, VaultConfig, VaultState,
+    PAUSE_REDEEM,
+};
+use crate::utils::{check_price_deviation, get_transfer_inverse_fee};
 
 use guardian::constants::{ACCESS_REGISTRY_SEED, ACCESS_ROLE_SEED};
 use guardian::state::{AccessRegistry, AccessRole, Role};
@@ -22,6 +28,7 @@ use usdu::state::UsduConfig;
 #[derive(Accounts)]
 pub struct RedeemUsduWithdrawCollateral<'info> {
     #[account(
+        mut,
         seeds = [VAULT_STATE_SEED],
         bump = vault_state.bump,
     )]
@@ -61,12 +68,27 @@ pub struct RedeemUsduWithdrawCollateral<'info> {
     )]
     pub collateral_withdrawer: Box<Account<'info, AccessRole>>,
 
-    /// CHECK: no need to checked
+    /// CHECK: checked against the primary participant allowlist
     pub benefactor: UncheckedAccount<'info>,
-    /// CHECK: no need to checked
+    /// CHECK: checked against the primary participant allowlist
     pub beneficiary: UncheckedAccount<'info>,
-    /// CHECK: no need to checked
+    #[account(
+        seeds = [VAULT_PRIMARY_PARTICIPANT_SEED, benefactor.key().as_ref()],
+        bump = benefactor_participant.bump,
+    )]
+    pub benefactor_participant: Box<Account<'info, PrimaryParticipant>>,
+    #[account(
+        seeds = [VAULT_PRIMARY_PARTICIPANT_SEED, beneficiary.key().as_ref()],
+        bump = beneficiary_participant.bump,
+    )]
+    pub beneficiary_participant: Box<Account<'info, PrimaryParticipant>>,
+    /// CHECK: checked against the custodian registry
     pub fund: UncheckedAccount<'info>,
+    #[account(
+        seeds = [VAULT_CUSTODIAN_SEED, collateral_token.key().as_ref(), fund.key().as_ref()],
+        bump = custodian.bump,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
     #[account(
         mut,
         associated_token::mint = usdu_token,
@@ -101,6 +123,16 @@ pub struct RedeemUsduWithdrawCollateral<'info> {
     pub benefactor_collateral_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub collateral_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [VAULT_COLLATERAL_CONFIG_SEED, collateral_token.key().as_ref()],
+        bump = collateral_config.bump,
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+    #[account(
+        seeds = [VAULT_PRICE_FEED_SEED, collateral_token.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
     #[account(mut)]
     pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
 
@@ -115,42 +147,72 @@ pub fn process_redeem_usdu_withdraw_collateral(
     collateral_amount: u64,
     usdu_amount: u64,
 ) -> Result<()> {
+    execute_redeem_usdu_withdraw_collateral(ctx.accounts, collateral_amount, usdu_amount)
+}
+
+pub(crate) fn execute_redeem_usdu_withdraw_collateral(
+    accounts: &mut RedeemUsduWithdrawCollateral,
+    collateral_amount: u64,
+    usdu_amount: u64,
+) -> Result<()> {
+    accounts.vault_config.check_not_paused(PAUSE_REDEEM)?;
     require!(
         collateral_amount > 0,
         VaultError::AmountMustBeGreaterThanZero
     );
     require!(usdu_amount > 0, VaultError::AmountMustBeGreaterThanZero);
 
-    require!(
-        is_supported_mint(&ctx.accounts.collateral_token)?,
-        VaultError::CollateralMismatch
-    );
+    accounts
+        .collateral_config
+        .check_redeem(accounts.collateral_token.decimals, collateral_amount)?;
+    // collateral only moves to or from approved custodians
+    accounts.custodian.check_active()?;
+    // both counterparties must be on the primary market allowlist
+    let now = Clock::get()?.unix_timestamp as u64;
+    accounts.benefactor_participant.check_redeem(now)?;
+    accounts.beneficiary_participant.check_redeem(now)?;
+
+    // the requested ratio must stay within the collateral's band around the price feed
+    let collateral_config = &accounts.collateral_config;
+    let price_feed = &accounts.price_feed;
+    price_feed.check_fresh(collateral_config.max_price_age)?;
+    check_price_deviation(
+        collateral_amount,
+        accounts.collateral_token.decimals,
+        usdu_amount,
+        accounts.usdu_token.decimals,
+        price_feed.price,
+        price_feed.decimals,
+        collateral_config.max_price_deviation_bps,
+    )?;
+
     require!(
         has_role(
-            &ctx.accounts.access_registry,
-            &ctx.accounts.collateral_withdrawer.to_account_info(),
-            &ctx.accounts.authority.to_account_info(),
+            &accounts.access_registry,
+            &accounts.collateral_withdrawer.to_account_info(),
+            &accounts.authority.to_account_info(),
             Role::CollateralWithdrawer,
         )?,
         VaultError::UnauthorizedRole
     );
     require!(
-        ctx.accounts.vault_state.vault_usdu_token_account.key()
-            == ctx.accounts.vault_usdu_token_account.key(),
+        accounts.vault_state.vault_usdu_token_account.key()
+            == accounts.vault_usdu_token_account.key(),
         VaultError::InvalidVaultUsduTokenAccount
     );
-    let vault_config = &ctx.accounts.vault_config;
+    let vault_config = &accounts.vault_config;
     require!(
         vault_config.is_initialized,
         VaultError::ConfigNotInitialized
     );
-    let vault_state = &ctx.accounts.vault_state;
+    let vault_state = &mut accounts.vault_state;
     require!(vault_state.is_initialized, VaultError::StateNotInitialized);
+    vault_state.record_redeem(vault_config, usdu_amount, Clock::get()?.slot)?;
 
     // can have TransferFeeConfig, but the fee must be 0
     let collateral_amount_with_fee = {
         let transfer_fee = get_transfer_inverse_fee(
-            &ctx.accounts.collateral_token.to_account_info(),
+            &accounts.collateral_token.to_account_info(),
             collateral_amount,
         )?;
         collateral_amount.checked_add(transfer_fee).unwrap()
@@ -162,46 +224,40 @@ pub fn process_redeem_usdu_withdraw_collateral(
 
     // delegate amount checked
     // fund should approve enough collateral amount to the vault
-    let delegate_collateral_amount = ctx.accounts.fund_collateral_token_account.delegated_amount;
+    let delegate_collateral_amount = accounts.fund_collateral_token_account.delegated_amount;
     require!(
         delegate_collateral_amount >= collateral_amount,
         VaultError::InsufficientCollateral
     );
     require!(
-        ctx.accounts
-            .fund_collateral_token_account
-            .delegate
-            .is_some(),
+        accounts.fund_collateral_token_account.delegate.is_some(),
         VaultError::NoDelegate
     );
     require!(
-        ctx.accounts
+        accounts
             .fund_collateral_token_account
             .delegate
             .unwrap()
-            .eq(&ctx.accounts.vault_config.key()),
+            .eq(&accounts.vault_config.key()),
         VaultError::DelegateAccountMismatch
     );
 
     // beneficiary should approve enough usdu amount to the vault
-    let delegate_usdu_amount = ctx.accounts.beneficiary_usdu_token_account.delegated_amount;
+    let delegate_usdu_amount = accounts.beneficiary_usdu_token_account.delegated_amount;
     require!(
         delegate_usdu_amount >= usdu_amount,
         VaultError::InsufficientUsdu
     );
     require!(
-        ctx.accounts
-            .beneficiary_usdu_token_account
-            .delegate
-            .is_some(),
+        accounts.beneficiary_usdu_token_account.delegate.is_some(),
         VaultError::NoDelegate
     );
     require!(
-        ctx.accounts
+        accounts
             .beneficiary_usdu_token_account
             .delegate
             .unwrap()
-            .eq(&ctx.accounts.vault_config.key()),
+            .eq(&accounts.vault_config.key()),
         VaultError::DelegateAccountMismatch
     );
 
@@ -210,64 +266,57 @@ pub fn process_redeem_usdu_withdraw_collateral(
     let signer_seeds = &[&[VAULT_CONFIG_SEED, config_bump][..]];
     transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             TransferChecked {
-                from: ctx
-                    .accounts
+                from: accounts
                     .fund_collateral_token_account
                     .to_account_info()
                     .clone(),
-                to: ctx
-                    .accounts
+                to: accounts
                     .benefactor_collateral_token_account
                     .to_account_info()
                     .clone(),
-                authority: ctx.accounts.vault_config.to_account_info().clone(),
-                mint: ctx.accounts.collateral_token.to_account_info().clone(),
+                authority: accounts.vault_config.to_account_info().clone(),
+                mint: accounts.collateral_token.to_account_info().clone(),
             },
             signer_seeds,
         ),
         collateral_amount,
-        ctx.accounts.collateral_token.decimals,
+        accounts.collateral_token.decimals,
     )?;
 
     // 2. transfer usdu from beneficiary to vault
     transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             TransferChecked {
-                from: ctx
-                    .accounts
+                from: accounts
                     .beneficiary_usdu_token_account
                     .to_account_info()
                     .clone(),
-                to: ctx
-                    .accounts
-                    .vault_usdu_token_account
-                    .to_account_info()
-                    .clone(),
-                authority: ctx.accounts.vault_config.to_account_info().clone(),
-                mint: ctx.accounts.usdu_token.to_account_info().clone(),
+                to: accounts.vault_usdu_token_account.to_account_info().clone(),
+                authority: accounts.vault_config.to_account_info().clone(),
+                mint: accounts.usdu_token.to_account_info().clone(),
             },
             signer_seeds,
         ),
         usdu_amount,
-        ctx.accounts.usdu_token.decimals,
+        accounts.usdu_token.decimals,
     )?;
 
     // 3. redeem usdu
     redeem_usdu(
         CpiContext::new_with_signer(
-            ctx.accounts.usdu_program.to_account_info(),
+            accounts.usdu_program.to_account_info(),
             RedeemUsdu {
-                caller: ctx.accounts.vault_config.to_account_info(),
-                access_registry: ctx.accounts.access_registry.to_account_info(),
-                access_role: ctx.accounts.usdu_redeemer.to_account_info(),
-                usdu_config: ctx.accounts.usdu_config.to_account_info(),
-                usdu_token: ctx.accounts.usdu_token.to_account_info(),
-                caller_token_account: ctx.accounts.vault_usdu_token_account.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
+                caller: accounts.vault_config.to_account_info(),
+                access_registry: accounts.access_registry.to_account_info(),
+                access_role: accounts.usdu_redeemer.to_account_info(),
+                usdu_config: accounts.usdu_config.to_account_info(),
+                usdu_token: accounts.usdu_token.to_account_info(),
+                caller_token_account: accounts.vault_usdu_token_account.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
             },
             signer_seeds,
         ),
@@ -275,9 +324,10 @@ pub fn process_redeem_usdu_withdraw_collateral(
     )?;
 
     emit!(RedeemUsduWithdrawCollateralEvent {
-        benefactor: ctx.accounts.benefactor.key(),
-        beneficiary: ctx.accounts.beneficiary.key(),
-        fund: ctx.accounts.fund.key(),
+        benefactor: accounts.benefactor.key(),
+        beneficiary: accounts.beneficiary.key(),
+        fund: accounts.fund.key(),
+        custodian: accounts.custodian.key(),
         collateral_amount,
         usdu_amount,
     });
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Cooldown;
+
+#[derive(Accounts)]
+pub struct CooldownView<'info> {
+    pub cooldown: Box<Account<'info, Cooldown>>,
+}
+
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct CooldownStatus {
+    pub owner: Pubkey,
+    pub underlying_token_amount: u64,
+    pub cooldown_end: u64,
+    pub is_active: bool,
+    pub remaining_seconds: u64,
+}
+
+pub fn process_cooldown_status(ctx: Context<CooldownView>) -> Result<CooldownStatus> {
+    let cooldown = &ctx.accounts.cooldown;
+    let now = Clock::get()?.unix_timestamp as u64;
+    Ok(CooldownStatus {
+        owner: cooldown.owner,
+        underlying_token_amount: cooldown.underlying_token_amount,
+        cooldown_end: cooldown.cooldown_end,
+        is_active: cooldown.is_cooldown_active(),
+        remaining_seconds: cooldown.cooldown_end.saturating_sub(now),
+    })
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::{
+    VAULT_CONFIG_SEED, VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED,
+    VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED, VAULT_STATE_SEED,
+};
+use crate::error::VaultError;
+use crate::state::{VaultConfig, VaultState};
+
+use susdu::state::SusduConfig;
+use susdu::SUSDU_CONFIG_SEED;
+use usdu::state::UsduConfig;
+use usdu::USDU_CONFIG_SEED;
+
+/// Read-only accounts for the solvency check, simulate the instruction to monitor the vault
+#[derive(Accounts)]
+pub struct CheckInvariants<'info> {
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [VAULT_STATE_SEED],
+        bump = vault_state.bump,
+        has_one = vault_stake_pool_usdu_token_account,
+        has_one = vault_silo_usdu_token_account,
+    )]
+    pub vault_state: Box<Account<'info, VaultState>>,
+    #[account(
+        seeds = [VAULT_STAKE_POOL_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_stake_pool_usdu_token_account_bump,
+    )]
+    pub vault_stake_pool_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [VAULT_SILO_USDU_TOKEN_ACCOUNT_SEED],
+        bump = vault_state.vault_silo_usdu_token_account_bump,
+    )]
+    pub vault_silo_usdu_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [USDU_CONFIG_SEED],
+        bump = usdu_config.bump,
+        seeds::program = usdu::id(),
+    )]
+    pub usdu_config: Box<Account<'info, UsduConfig>>,
+    #[account(
+        seeds = [SUSDU_CONFIG_SEED],
+        bump = susdu_config.bump,
+        seeds::program = susdu::id(),
+    )]
+    pub susdu_config: Box<Account<'info, SusduConfig>>,
+    #[account(address = vault_config.usdu @ VaultError::InvalidUsduToken)]
+    pub usdu_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(address = vault_config.susdu @ VaultError::InvalidSusduToken)]
+    pub susdu_token: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// Fails unless the stake pool and silo cover the vault counters and the token configs match their mints
+pub fn process_check_invariants(ctx: Context<CheckInvariants>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    check_vault_invariants(
+        &accounts.vault_config,
+        &accounts.vault_stake_pool_usdu_token_account,
+        &accounts.vault_silo_usdu_token_account,
+    )?;
+    require!(
+        accounts.usdu_config.total_supply == accounts.usdu_token.supply,
+        VaultError::UsduSupplyMismatch
+    );
+    require!(
+        accounts.susdu_config.total_supply == accounts.susdu_token.supply,
+        VaultError::SusduSupplyMismatch
+    );
+    Ok(())
+}
+
+/// Solvency guard that other instructions can append after moving vault USDU
+pub(crate) fn check_vault_invariants(
+    vault_config: &VaultConfig,
+    vault_stake_pool_usdu_token_account: &TokenAccount,
+    vault_silo_usdu_token_account: &TokenAccount,
+) -> Result<()> {
+    require!(
+        vault_stake_pool_usdu_token_account.amount >= vault_config.total_staked_usdu_supply,
+        VaultError::InsufficientStakePoolUsdu
+    );
+    require!(
+        vault_silo_usdu_token_account.amount >= vault_config.total_cooldown_usdu_amount,
+        VaultError::InsufficientUsduForCooldowns
+    );
+    Ok(())
+}
@@ -0,0 +1,7 @@
+mod cooldown_status;
+mod invariants;
+mod preview;
+
+pub use cooldown_status::*;
+pub use invariants::*;
+pub use preview::*;
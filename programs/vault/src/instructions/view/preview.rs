@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::VAULT_CONFIG_SEED;
+use crate::error::VaultError;
+use crate::state::VaultConfig;
+
+use susdu::state::SusduConfig;
+use susdu::SUSDU_CONFIG_SEED;
+
+/// Read-only accounts for the vault views, results are returned with `set_return_data`
+#[derive(Accounts)]
+pub struct VaultView<'info> {
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+    #[account(
+        seeds = [SUSDU_CONFIG_SEED],
+        bump = susdu_config.bump,
+        seeds::program = susdu::id(),
+    )]
+    pub susdu_config: Box<Account<'info, SusduConfig>>,
+    #[account(address = vault_config.susdu @ VaultError::InvalidSusduToken)]
+    pub susdu_token: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// SUSDU minted for `usdu_amount`, matches `stake_usdu_mint_susdu`
+pub fn process_preview_deposit(ctx: Context<VaultView>, usdu_amount: u64) -> Result<u64> {
+    let vault_config = &ctx.accounts.vault_config;
+    let susdu_amount =
+        vault_config.preview_deposit(usdu_amount, ctx.accounts.susdu_config.total_supply);
+    if !vault_config.has_initial_deposit {
+        return Ok(susdu_amount.saturating_sub(vault_config.initial_dead_shares()));
+    }
+    Ok(susdu_amount)
+}
+
+/// USDU put into cooldown for `susdu_amount`, matches `unstake_susdu`
+pub fn process_preview_redeem(ctx: Context<VaultView>, susdu_amount: u64) -> Result<u64> {
+    let vault_config = &ctx.accounts.vault_config;
+    Ok(vault_config.preview_redeem(susdu_amount, ctx.accounts.susdu_config.total_supply))
+}
+
+pub fn process_total_assets(ctx: Context<VaultView>) -> Result<u64> {
+    Ok(ctx.accounts.vault_config.total_assets())
+}
+
+pub fn process_unvested_amount(ctx: Context<VaultView>) -> Result<u64> {
+    Ok(ctx.accounts.vault_config.get_unvested_amount())
+}
+
+/// USDU redeemable for one whole SUSDU
+pub fn process_share_price(ctx: Context<VaultView>) -> Result<u64> {
+    let vault_config = &ctx.accounts.vault_config;
+    let one_share = 10u64.pow(ctx.accounts.susdu_token.decimals.into());
+    if !vault_config.has_initial_deposit {
+        return Ok((one_share as u128 / vault_config.virtual_shares()) as u64);
+    }
+    Ok(vault_config.preview_redeem(one_share, ctx.accounts.susdu_config.total_supply))
+}
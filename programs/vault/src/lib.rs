@@ -1,4 +1,4 @@
-#[allow(unexpected_cfgs)]
+#![allow(unexpected_cfgs)]
 pub mod constants;
 pub mod error;
 pub mod events;
@@ -54,8 +54,112 @@ pub mod vault {
         process_init_vault_state_slio_usdu_token_account(ctx)
     }
 
-    pub fn adjust_cooldown(ctx: Context<AdjustCooldown>, cooldown_duration: u64) -> Result<()> {
-        process_adjust_cooldown(ctx, cooldown_duration)
+    pub fn migrate_vault_config(ctx: Context<MigrateVaultConfig>) -> Result<()> {
+        process_migrate_vault_config(ctx)
+    }
+
+    pub fn migrate_vault_state(ctx: Context<MigrateVaultState>) -> Result<()> {
+        process_migrate_vault_state(ctx)
+    }
+
+    pub fn add_collateral(
+        ctx: Context<AddCollateral>,
+        max_mint_per_tx: u64,
+        max_redeem_per_tx: u64,
+    ) -> Result<()> {
+        process_add_collateral(ctx, max_mint_per_tx, max_redeem_per_tx)
+    }
+
+    pub fn set_collateral_status(
+        ctx: Context<UpdateCollateral>,
+        status: CollateralStatus,
+    ) -> Result<()> {
+        process_set_collateral_status(ctx, status)
+    }
+
+    pub fn set_collateral_limits(
+        ctx: Context<UpdateCollateral>,
+        max_mint_per_tx: u64,
+        max_redeem_per_tx: u64,
+    ) -> Result<()> {
+        process_set_collateral_limits(ctx, max_mint_per_tx, max_redeem_per_tx)
+    }
+
+    pub fn set_collateral_price_band(
+        ctx: Context<UpdateCollateral>,
+        max_price_deviation_bps: u16,
+        max_price_age: u64,
+    ) -> Result<()> {
+        process_set_collateral_price_band(ctx, max_price_deviation_bps, max_price_age)
+    }
+
+    pub fn add_custodian(
+        ctx: Context<AddCustodian>,
+        routing_weight_bps: Option<u16>,
+    ) -> Result<()> {
+        process_add_custodian(ctx, routing_weight_bps)
+    }
+
+    pub fn update_custodian(
+        ctx: Context<UpdateCustodian>,
+        is_active: bool,
+        routing_weight_bps: Option<u16>,
+    ) -> Result<()> {
+        process_update_custodian(ctx, is_active, routing_weight_bps)
+    }
+
+    pub fn set_primary_participant(
+        ctx: Context<SetPrimaryParticipant>,
+        can_mint: bool,
+        can_redeem: bool,
+        expiry: Option<u64>,
+    ) -> Result<()> {
+        process_set_primary_participant(ctx, can_mint, can_redeem, expiry)
+    }
+
+    pub fn set_rate_limits(
+        ctx: Context<SetRateLimits>,
+        max_mint_per_window: u64,
+        max_redeem_per_window: u64,
+        rate_limit_window_slots: u64,
+    ) -> Result<()> {
+        process_set_rate_limits(
+            ctx,
+            max_mint_per_window,
+            max_redeem_per_window,
+            rate_limit_window_slots,
+        )
+    }
+
+    pub fn set_vesting_period(ctx: Context<SetVestingPeriod>, vesting_period: u64) -> Result<()> {
+        process_set_vesting_period(ctx, vesting_period)
+    }
+
+    pub fn set_decimals_offset(ctx: Context<SetDecimalsOffset>, decimals_offset: u8) -> Result<()> {
+        process_set_decimals_offset(ctx, decimals_offset)
+    }
+
+    pub fn set_reward_fee(ctx: Context<SetRewardFee>, reward_fee_bps: u16) -> Result<()> {
+        process_set_reward_fee(ctx, reward_fee_bps)
+    }
+
+    pub fn report_loss(ctx: Context<ReportLoss>, usdu_amount: u64) -> Result<()> {
+        process_report_loss(ctx, usdu_amount)
+    }
+
+    pub fn set_max_loss_per_report(
+        ctx: Context<SetMaxLossPerReport>,
+        max_loss_per_report_bps: u16,
+    ) -> Result<()> {
+        process_set_max_loss_per_report(ctx, max_loss_per_report_bps)
+    }
+
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        price: u64,
+        decimals: u8,
+    ) -> Result<()> {
+        process_update_price_feed(ctx, price, decimals)
     }
 
     pub fn deposit_collateral_mint_usdu(
@@ -74,19 +178,85 @@ pub mod vault {
         process_redeem_usdu_withdraw_collateral(ctx, collateral_amount, usdu_amount)
     }
 
-    pub fn stake_usdu_mint_susdu(ctx: Context<StakeUsduMintSusdu>, usdu_amount: u64) -> Result<()> {
-        process_stake_usdu_mint_susdu(ctx, usdu_amount)
+    pub fn mint_usdu_with_order(ctx: Context<MintUsduWithOrder>, order: MintOrder) -> Result<()> {
+        process_mint_usdu_with_order(ctx, order)
+    }
+
+    pub fn redeem_usdu_with_order(
+        ctx: Context<RedeemUsduWithOrder>,
+        order: RedeemOrder,
+    ) -> Result<()> {
+        process_redeem_usdu_with_order(ctx, order)
+    }
+
+    pub fn stake_usdu_mint_susdu(
+        ctx: Context<StakeUsduMintSusdu>,
+        usdu_amount: u64,
+        min_susdu_out: u64,
+    ) -> Result<()> {
+        process_stake_usdu_mint_susdu(ctx, usdu_amount, min_susdu_out)
+    }
+
+    pub fn mint_susdu_exact(
+        ctx: Context<StakeUsduMintSusdu>,
+        susdu_amount: u64,
+        max_usdu_in: u64,
+    ) -> Result<()> {
+        process_mint_susdu_exact(ctx, susdu_amount, max_usdu_in)
     }
 
     pub fn unstake_susdu<'info>(
         ctx: Context<'_, '_, '_, 'info, UnstakeSusdu<'info>>,
         susdu_amount: u64,
+        min_usdu_out: u64,
+    ) -> Result<()> {
+        process_unstake_susdu(ctx, susdu_amount, min_usdu_out)
+    }
+
+    pub fn unstake_usdu_exact<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeSusdu<'info>>,
+        usdu_amount: u64,
+        max_susdu_in: u64,
+    ) -> Result<()> {
+        process_unstake_usdu_exact(ctx, usdu_amount, max_susdu_in)
+    }
+
+    pub fn unstake_susdu_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeSusduDirect<'info>>,
+        susdu_amount: u64,
+        min_usdu_out: u64,
+    ) -> Result<()> {
+        process_unstake_susdu_direct(ctx, susdu_amount, min_usdu_out)
+    }
+
+    pub fn withdraw_usdu(ctx: Context<WithdrawUsdu>, usdu_amount: u64) -> Result<()> {
+        process_withdraw_usdu(ctx, usdu_amount)
+    }
+
+    pub fn crank_withdraw_usdu<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankWithdrawUsdu<'info>>,
     ) -> Result<()> {
-        process_unstake_susdu(ctx, susdu_amount)
+        process_crank_withdraw_usdu(ctx)
     }
 
-    pub fn withdraw_usdu(ctx: Context<WithdrawUsdu>) -> Result<()> {
-        process_withdraw_usdu(ctx)
+    pub fn set_cooldown_receiver(ctx: Context<SetCooldownReceiver>) -> Result<()> {
+        process_set_cooldown_receiver(ctx)
+    }
+
+    pub fn close_cooldown(ctx: Context<CloseCooldown>) -> Result<()> {
+        process_close_cooldown(ctx)
+    }
+
+    pub fn migrate_legacy_cooldown(ctx: Context<MigrateLegacyCooldown>) -> Result<()> {
+        process_migrate_legacy_cooldown(ctx)
+    }
+
+    pub fn cancel_cooldown(
+        ctx: Context<CancelCooldown>,
+        usdu_amount: u64,
+        min_susdu_out: u64,
+    ) -> Result<()> {
+        process_cancel_cooldown(ctx, usdu_amount, min_susdu_out)
     }
 
     pub fn distribute_usdu_reward(
@@ -96,38 +266,40 @@ pub mod vault {
         process_distribute_usdu_reward(ctx, usdu_amount)
     }
 
-    pub fn emergency_withdraw_vault_susdu<'info>(
-        ctx: Context<'_, '_, '_, 'info, EmergencyWithdrawVaultSusdu<'info>>,
-        amount: u64,
-    ) -> Result<()> {
-        process_emergency_withdraw_vault_susdu(ctx, amount)
+    pub fn queue_action(ctx: Context<QueueAction>, action: VaultAction) -> Result<()> {
+        process_queue_action(ctx, action)
     }
 
-    pub fn emergency_withdraw_vault_usdu(
-        ctx: Context<EmergencyWithdrawVaultUsdu>,
-        amount: u64,
-    ) -> Result<()> {
-        process_emergency_withdraw_vault_usdu(ctx, amount)
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        process_cancel_action(ctx)
     }
 
-    pub fn emergency_withdraw_vault_slio_usdu(
-        ctx: Context<EmergencyWithdrawVaultSlioUsdu>,
-        amount: u64,
+    pub fn execute_action<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteAction<'info>>,
     ) -> Result<()> {
-        process_emergency_withdraw_vault_slio_usdu(ctx, amount)
+        process_execute_action(ctx)
     }
 
-    pub fn emergency_withdraw_vault_stake_pool_usdu(
-        ctx: Context<EmergencyWithdrawVaultStakePoolUsdu>,
-        amount: u64,
+    pub fn set_action_delay(
+        ctx: Context<SetActionDelay>,
+        action_type: VaultActionType,
+        delay: u64,
     ) -> Result<()> {
-        process_emergency_withdraw_vault_stake_pool_usdu(ctx, amount)
+        process_set_action_delay(ctx, action_type, delay)
     }
 
     pub fn redistribute_locked(ctx: Context<RedistributeLocked>) -> Result<()> {
         process_redistribute_locked(ctx)
     }
 
+    pub fn pause(ctx: Context<Pause>, flags: u8) -> Result<()> {
+        process_pause(ctx, flags)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+        process_unpause(ctx, flags)
+    }
+
     pub fn propose_new_admin(ctx: Context<ProposeNewAdmin>) -> Result<()> {
         process_propose_new_admin(ctx)
     }
@@ -136,10 +308,35 @@ pub mod vault {
         process_accept_admin_transfer(ctx)
     }
 
-    pub fn reset_total_cooldown_usdu_amount(
-        ctx: Context<EmergencyResetCooldownAmount>,
-        new_amount: u64,
-    ) -> Result<()> {
-        process_reset_total_cooldown_usdu_amount(ctx, new_amount)
+    pub fn sync_vault_accounting(ctx: Context<SyncVaultAccounting>) -> Result<()> {
+        process_sync_vault_accounting(ctx)
+    }
+
+    pub fn preview_deposit(ctx: Context<VaultView>, usdu_amount: u64) -> Result<u64> {
+        process_preview_deposit(ctx, usdu_amount)
+    }
+
+    pub fn preview_redeem(ctx: Context<VaultView>, susdu_amount: u64) -> Result<u64> {
+        process_preview_redeem(ctx, susdu_amount)
+    }
+
+    pub fn total_assets(ctx: Context<VaultView>) -> Result<u64> {
+        process_total_assets(ctx)
+    }
+
+    pub fn unvested_amount(ctx: Context<VaultView>) -> Result<u64> {
+        process_unvested_amount(ctx)
+    }
+
+    pub fn share_price(ctx: Context<VaultView>) -> Result<u64> {
+        process_share_price(ctx)
+    }
+
+    pub fn check_invariants(ctx: Context<CheckInvariants>) -> Result<()> {
+        process_check_invariants(ctx)
+    }
+
+    pub fn cooldown_status(ctx: Context<CooldownView>) -> Result<CooldownStatus> {
+        process_cooldown_status(ctx)
     }
 }
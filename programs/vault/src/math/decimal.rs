@@ -275,7 +275,7 @@ mod test {
         assert_eq!(c, Decimal::from_scaled_val(300_u128));
 
         let c = a.try_sub(b);
-        assert_eq!(c.is_err(), true);
+        assert!(c.is_err());
 
         let c = b.try_sub(a).unwrap();
         assert_eq!(c, Decimal::from_scaled_val(100_u128));
@@ -286,8 +286,8 @@ mod test {
         let c = b.try_div(b).unwrap();
         assert_eq!(c, Decimal::from_scaled_val(1000000000000000000));
 
-        assert_eq!(b.try_div(0_u64).is_err(), true);
-        assert_eq!(b.try_div(Decimal::zero()).is_err(), true);
+        assert!(b.try_div(0_u64).is_err());
+        assert!(b.try_div(Decimal::zero()).is_err());
 
         let c = b.try_mul(2_u64).unwrap();
         assert_eq!(c, Decimal::from_scaled_val(400_u128));
@@ -344,13 +344,13 @@ mod test {
     fn overflows() {
         let a = Decimal(U192::MAX);
         let zero = Decimal::zero();
-        assert_eq!(a.try_add(a).is_err(), true);
-        assert_eq!(zero.try_sub(a).is_err(), true);
-        assert_eq!(a.try_mul(a).is_err(), true);
-        assert_eq!(a.try_div(Decimal::from_percent(1)).is_err(), true);
-        assert_eq!(a.try_div(Rate::from_percent(1)).is_err(), true);
-        assert_eq!(a.try_mul(u64::MAX).is_err(), true);
-        assert_eq!(a.try_div(0_u64).is_err(), true);
+        assert!(a.try_add(a).is_err());
+        assert!(zero.try_sub(a).is_err());
+        assert!(a.try_mul(a).is_err());
+        assert!(a.try_div(Decimal::from_percent(1)).is_err());
+        assert!(a.try_div(Rate::from_percent(1)).is_err());
+        assert!(a.try_mul(u64::MAX).is_err());
+        assert!(a.try_div(0_u64).is_err());
     }
     #[test]
     fn test_constant_funcs() {
@@ -259,7 +259,7 @@ mod test {
         assert_eq!(c, Rate::from_scaled_val(300_u64));
 
         let c = a.try_sub(b);
-        assert_eq!(c.is_err(), true);
+        assert!(c.is_err());
 
         let c = b.try_sub(a).unwrap();
         assert_eq!(c, Rate::from_scaled_val(100_u64));
@@ -270,8 +270,8 @@ mod test {
         let c = b.try_div(b).unwrap();
         assert_eq!(c, Rate::from_scaled_val(1000000000000000000));
 
-        assert_eq!(b.try_div(0_u64).is_err(), true);
-        assert_eq!(b.try_div(Rate::zero()).is_err(), true);
+        assert!(b.try_div(0_u64).is_err());
+        assert!(b.try_div(Rate::zero()).is_err());
 
         let c = b.try_mul(2_u64).unwrap();
         assert_eq!(c, Rate::from_scaled_val(400_u64));
@@ -284,12 +284,12 @@ mod test {
     fn overflows() {
         let a = Rate(U128::MAX);
         let zero = Rate::zero();
-        assert_eq!(a.try_add(a).is_err(), true);
-        assert_eq!(zero.try_sub(a).is_err(), true);
-        assert_eq!(a.try_mul(a).is_err(), true);
-        assert_eq!(a.try_div(Rate::from_percent(1)).is_err(), true);
-        assert_eq!(a.try_mul(u64::MAX).is_err(), true);
-        assert_eq!(a.try_div(0_u64).is_err(), true);
+        assert!(a.try_add(a).is_err());
+        assert!(zero.try_sub(a).is_err());
+        assert!(a.try_mul(a).is_err());
+        assert!(a.try_div(Rate::from_percent(1)).is_err());
+        assert!(a.try_mul(u64::MAX).is_err());
+        assert!(a.try_div(0_u64).is_err());
     }
     #[test]
     fn test_constant_funcs() {
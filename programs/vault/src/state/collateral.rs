@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum CollateralStatus {
+    /// Collateral can be deposited and redeemed
+    Active,
+    /// Collateral is temporarily frozen, neither deposit nor redeem is allowed
+    Disabled,
+    /// Collateral is being wound down, only redeem is allowed and it can not be re-enabled
+    Retired,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CollateralConfig {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub status: CollateralStatus,
+
+    // limits are denominated in collateral token units
+    pub max_mint_per_tx: u64,
+    pub max_redeem_per_tx: u64,
+
+    // allowed distance between the requested ratio and the price feed
+    pub max_price_deviation_bps: u16,
+    pub max_price_age: u64,
+}
+
+impl CollateralConfig {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn can_mint(&self) -> bool {
+        self.is_initialized && self.status == CollateralStatus::Active
+    }
+
+    pub fn can_redeem(&self) -> bool {
+        self.is_initialized
+            && (self.status == CollateralStatus::Active || self.status == CollateralStatus::Retired)
+    }
+
+    pub fn check_mint(&self, decimals: u8, collateral_amount: u64) -> Result<()> {
+        require!(self.is_initialized, VaultError::CollateralNotRegistered);
+        require!(self.can_mint(), VaultError::CollateralMintDisabled);
+        require!(
+            self.decimals == decimals,
+            VaultError::CollateralDecimalsMismatch
+        );
+        require!(
+            collateral_amount <= self.max_mint_per_tx,
+            VaultError::CollateralMintLimitExceeded
+        );
+        Ok(())
+    }
+
+    pub fn check_redeem(&self, decimals: u8, collateral_amount: u64) -> Result<()> {
+        require!(self.is_initialized, VaultError::CollateralNotRegistered);
+        require!(self.can_redeem(), VaultError::CollateralRedeemDisabled);
+        require!(
+            self.decimals == decimals,
+            VaultError::CollateralDecimalsMismatch
+        );
+        require!(
+            collateral_amount <= self.max_redeem_per_tx,
+            VaultError::CollateralRedeemLimitExceeded
+        );
+        Ok(())
+    }
+}
@@ -1,17 +1,54 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    DEFAULT_ACTION_DELAY, DEFAULT_MAX_ACCOUNTING_DRIFT, DEFAULT_MAX_LOSS_PER_REPORT_BPS,
+    DEFAULT_RATE_LIMIT_WINDOW_SLOTS,
+};
 use crate::error::VaultError;
 use crate::math::Rounding;
+use crate::state::{VaultActionType, VAULT_ACTION_TYPE_COUNT};
 
+/// Default vesting period of a new vault config
 #[constant]
 pub const VESTING_PERIOD: u64 = 60 * 60 * 8; // 8 hours
 
+#[constant]
+pub const MIN_VESTING_PERIOD: u64 = 60 * 60; // 1 hour
+
+#[constant]
+pub const MAX_VESTING_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days
+
 #[constant]
 pub const MIN_SHARES: u64 = 10_u64.pow(6);
 
 #[constant]
 pub const INITIAL_DEPOSIT_AMOUNT: u64 = 1000 * 10_u64.pow(6);
 
+#[constant]
+pub const MAX_DECIMALS_OFFSET: u8 = 3;
+
+#[constant]
+pub const PAUSE_MINT: u8 = 1 << 0;
+
+#[constant]
+pub const PAUSE_REDEEM: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_STAKE: u8 = 1 << 2;
+
+#[constant]
+pub const PAUSE_UNSTAKE: u8 = 1 << 3;
+
+#[constant]
+pub const PAUSE_WITHDRAW: u8 = 1 << 4;
+
+#[constant]
+pub const PAUSE_REWARD: u8 = 1 << 5;
+
+#[constant]
+pub const PAUSE_ALL: u8 =
+    PAUSE_MINT | PAUSE_REDEEM | PAUSE_STAKE | PAUSE_UNSTAKE | PAUSE_WITHDRAW | PAUSE_REWARD;
+
 #[account]
 #[derive(Debug, Default)]
 pub struct VaultConfig {
@@ -30,6 +67,28 @@ pub struct VaultConfig {
     pub vesting_amount: u64,
     pub last_distribution_timestamp: u64,
     pub has_initial_deposit: bool,
+
+    pub max_mint_per_window: u64,
+    pub max_redeem_per_window: u64,
+    pub rate_limit_window_slots: u64,
+
+    pub paused_flags: u8,
+    pub no_cooldown_mode: bool,
+    pub keeper_tip_bps: u16,
+    pub vesting_period: u64,
+
+    pub reward_fee_bps: u16,
+    pub treasury_usdu_token_account: Pubkey,
+
+    pub max_loss_per_report_bps: u16,
+    pub last_loss_report_timestamp: u64,
+
+    pub decimals_offset: u8,
+
+    pub action_delays: [u64; VAULT_ACTION_TYPE_COUNT],
+    pub next_action_id: u64,
+
+    pub max_accounting_drift: u64,
 }
 
 #[account]
@@ -49,10 +108,150 @@ pub struct VaultState {
     pub vault_usdu_token_account: Pubkey,
     pub vault_stake_pool_usdu_token_account: Pubkey,
     pub vault_silo_usdu_token_account: Pubkey,
+
+    pub rate_limit_window_start_slot: u64,
+    pub minted_in_window: u64,
+    pub redeemed_in_window: u64,
+}
+
+/// `VaultConfig` layout of the first deployment, read by `migrate_vault_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyVaultConfig {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub usdu: Pubkey,
+    pub susdu: Pubkey,
+    pub access_registry: Pubkey,
+
+    pub cooldown_duration: u64,
+    pub total_staked_usdu_supply: u64,
+    pub total_cooldown_usdu_amount: u64,
+    pub vesting_amount: u64,
+    pub last_distribution_timestamp: u64,
+    pub has_initial_deposit: bool,
+}
+
+/// `VaultState` layout of the first deployment, read by `migrate_vault_state`
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyVaultState {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub admin: Pubkey,
+
+    pub vault_susdu_token_account_bump: u8,
+    pub vault_usdu_token_account_bump: u8,
+    pub vault_stake_pool_usdu_token_account_bump: u8,
+    pub vault_silo_usdu_token_account_bump: u8,
+
+    pub vault_susdu_token_account: Pubkey,
+    pub vault_usdu_token_account: Pubkey,
+    pub vault_stake_pool_usdu_token_account: Pubkey,
+    pub vault_silo_usdu_token_account: Pubkey,
+}
+
+/// Fields appended after the first deployment take the defaults of `init_vault_config`
+impl From<LegacyVaultConfig> for VaultConfig {
+    fn from(legacy: LegacyVaultConfig) -> Self {
+        Self {
+            is_initialized: legacy.is_initialized,
+            bump: legacy.bump,
+            admin: legacy.admin,
+            pending_admin: legacy.pending_admin,
+            usdu: legacy.usdu,
+            susdu: legacy.susdu,
+            access_registry: legacy.access_registry,
+            cooldown_duration: legacy.cooldown_duration,
+            total_staked_usdu_supply: legacy.total_staked_usdu_supply,
+            total_cooldown_usdu_amount: legacy.total_cooldown_usdu_amount,
+            vesting_amount: legacy.vesting_amount,
+            last_distribution_timestamp: legacy.last_distribution_timestamp,
+            has_initial_deposit: legacy.has_initial_deposit,
+            max_mint_per_window: u64::MAX,
+            max_redeem_per_window: u64::MAX,
+            rate_limit_window_slots: DEFAULT_RATE_LIMIT_WINDOW_SLOTS,
+            vesting_period: VESTING_PERIOD,
+            max_loss_per_report_bps: DEFAULT_MAX_LOSS_PER_REPORT_BPS,
+            action_delays: [DEFAULT_ACTION_DELAY; VAULT_ACTION_TYPE_COUNT],
+            max_accounting_drift: DEFAULT_MAX_ACCOUNTING_DRIFT,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LegacyVaultState> for VaultState {
+    fn from(legacy: LegacyVaultState) -> Self {
+        Self {
+            is_initialized: legacy.is_initialized,
+            bump: legacy.bump,
+            admin: legacy.admin,
+            vault_susdu_token_account_bump: legacy.vault_susdu_token_account_bump,
+            vault_usdu_token_account_bump: legacy.vault_usdu_token_account_bump,
+            vault_stake_pool_usdu_token_account_bump: legacy
+                .vault_stake_pool_usdu_token_account_bump,
+            vault_silo_usdu_token_account_bump: legacy.vault_silo_usdu_token_account_bump,
+            vault_susdu_token_account: legacy.vault_susdu_token_account,
+            vault_usdu_token_account: legacy.vault_usdu_token_account,
+            vault_stake_pool_usdu_token_account: legacy.vault_stake_pool_usdu_token_account,
+            vault_silo_usdu_token_account: legacy.vault_silo_usdu_token_account,
+            ..Default::default()
+        }
+    }
 }
 
 impl VaultState {
     pub const SIZE: usize = 8 + std::mem::size_of::<Self>();
+
+    /// Windows are aligned to multiples of `window_slots`, the usage counters reset when a new window starts
+    fn roll_rate_limit_window(&mut self, window_slots: u64, slot: u64) {
+        let window_start_slot = slot - slot % window_slots;
+        if window_start_slot != self.rate_limit_window_start_slot {
+            self.rate_limit_window_start_slot = window_start_slot;
+            self.minted_in_window = 0;
+            self.redeemed_in_window = 0;
+        }
+    }
+
+    pub fn record_mint(
+        &mut self,
+        vault_config: &VaultConfig,
+        usdu_amount: u64,
+        slot: u64,
+    ) -> Result<()> {
+        self.roll_rate_limit_window(vault_config.rate_limit_window_slots, slot);
+        let minted_in_window = self
+            .minted_in_window
+            .checked_add(usdu_amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            minted_in_window <= vault_config.max_mint_per_window,
+            VaultError::MintRateLimitExceeded
+        );
+        self.minted_in_window = minted_in_window;
+        Ok(())
+    }
+
+    pub fn record_redeem(
+        &mut self,
+        vault_config: &VaultConfig,
+        usdu_amount: u64,
+        slot: u64,
+    ) -> Result<()> {
+        self.roll_rate_limit_window(vault_config.rate_limit_window_slots, slot);
+        let redeemed_in_window = self
+            .redeemed_in_window
+            .checked_add(usdu_amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            redeemed_in_window <= vault_config.max_redeem_per_window,
+            VaultError::RedeemRateLimitExceeded
+        );
+        self.redeemed_in_window = redeemed_in_window;
+        Ok(())
+    }
 }
 
 impl VaultConfig {
@@ -60,11 +259,14 @@ impl VaultConfig {
 
     pub fn total_assets(&self) -> u64 {
         let unvested_amount = self.get_unvested_amount();
-        let result = self
-            .total_staked_usdu_supply
+        self.total_staked_usdu_supply
             .checked_sub(unvested_amount)
-            .expect("Math overflow");
-        result
+            .expect("Math overflow")
+    }
+
+    pub fn check_not_paused(&self, flag: u8) -> Result<()> {
+        require!(self.paused_flags & flag == 0, VaultError::OperationPaused);
+        Ok(())
     }
 
     pub fn check_initial_deposit(&self, deposit_amount: u64) -> Result<()> {
@@ -88,30 +290,48 @@ impl VaultConfig {
         Ok(())
     }
 
+    pub fn action_delay(&self, action_type: VaultActionType) -> u64 {
+        self.action_delays[action_type as usize]
+    }
+
+    /// ERC4626 `10 ** _decimalsOffset()`, the virtual shares backing the virtual asset
+    pub fn virtual_shares(&self) -> u128 {
+        10u128.pow(self.decimals_offset as u32)
+    }
+
+    /// Shares minted to the burn account on the initial deposit
+    pub fn initial_dead_shares(&self) -> u64 {
+        MIN_SHARES
+            .checked_mul(10u64.pow(self.decimals_offset as u32))
+            .expect("Math overflow")
+    }
+
     pub fn get_unvested_amount(&self) -> u64 {
+        if self.vesting_amount == 0 {
+            return 0;
+        }
         let time_since_last_distribution = (Clock::get().unwrap().unix_timestamp as u128)
             .checked_sub(self.last_distribution_timestamp as u128)
             .expect("Math overflow");
-        if time_since_last_distribution >= VESTING_PERIOD as u128 {
-            return 0;
+        if time_since_last_distribution >= self.vesting_period as u128 {
+            0
         } else {
             let vesting_amount = self.vesting_amount as u128;
-            let vesting_period = VESTING_PERIOD as u128;
-            let result = (vesting_period
+            let vesting_period = self.vesting_period as u128;
+            (vesting_period
                 .checked_sub(time_since_last_distribution)
                 .expect("Math overflow")
                 .checked_mul(vesting_amount)
                 .expect("Math overflow")
                 .checked_div(vesting_period)
-                .expect("Math overflow")) as u64;
-            return result;
+                .expect("Math overflow")) as u64
         }
     }
 
     /// Convert assets to shares
     /*
         /// rounding is Math.Rounding.Floor
-        /// _decimalsOffset() is 0
+        /// _decimalsOffset() is vault_config.decimals_offset
         _convertToShares(uint256 assets, Math.Rounding rounding) internal pure returns (uint256) {
             return assets.mulDiv(totalShares() + 10 ** _decimalsOffset(), totalAssets() + 1, rounding);
         }
@@ -122,7 +342,7 @@ impl VaultConfig {
     // for increased security.
     fn convert_to_shares(&self, assets: u64, total_shares: u64, rounding: Rounding) -> u64 {
         let numerator = (assets as u128)
-            .checked_mul(total_shares as u128 + 1u128)
+            .checked_mul(total_shares as u128 + self.virtual_shares())
             .expect("Math overflow");
         let denominator = self.total_assets() as u128 + 1u128;
         let result = numerator / denominator;
@@ -143,7 +363,7 @@ impl VaultConfig {
     /// Convert shares to assets
     /*
         /// rounding is Math.Rounding.Floor
-        /// _decimalsOffset() is 0
+        /// _decimalsOffset() is vault_config.decimals_offset
         _convertToAssets(uint256 shares, Math.Rounding rounding) internal pure returns (uint256) {
             return shares.mulDiv(totalAssets() + 1, totalShares() + 10 ** _decimalsOffset(), rounding);
         }
@@ -156,7 +376,7 @@ impl VaultConfig {
         let numerator = (shares as u128)
             .checked_mul(self.total_assets() as u128 + 1u128)
             .expect("Math overflow");
-        let denominator = total_shares as u128 + 1u128;
+        let denominator = total_shares as u128 + self.virtual_shares();
         let result = numerator / denominator;
         match rounding {
             Rounding::Floor => result as u64,
@@ -184,8 +404,131 @@ impl VaultConfig {
         self.convert_to_assets(shares, total_shares, Rounding::Floor)
     }
 
+    /// ERC4626 preview mint
+    /// Uses Rounding::Ceil so the caller never pays less USDU than the shares are worth
+    pub(crate) fn preview_mint(&self, shares: u64, total_shares: u64) -> u64 {
+        self.convert_to_assets(shares, total_shares, Rounding::Ceil)
+    }
+
+    /// ERC4626 preview withdraw
+    /// Uses Rounding::Ceil so the caller never burns less SUSDU than the assets are worth
+    pub(crate) fn preview_withdraw(&self, assets: u64, total_shares: u64) -> u64 {
+        self.convert_to_shares(assets, total_shares, Rounding::Ceil)
+    }
+
     /// ERC4626 max_deposit
     pub(crate) fn max_deposit(&self) -> u64 {
         u64::MAX
     }
+
+    /// ERC4626 max_mint
+    pub(crate) fn max_mint(&self) -> u64 {
+        u64::MAX
+    }
+
+    /// ERC4626 max_withdraw
+    pub(crate) fn max_withdraw(&self, owner_shares: u64, total_shares: u64) -> u64 {
+        self.preview_redeem(owner_shares, total_shares)
+    }
+
+    /// ERC4626 max_redeem
+    pub(crate) fn max_redeem(&self, owner_shares: u64) -> u64 {
+        owner_shares
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rate_limit_resets_per_window() {
+        let vault_config = VaultConfig {
+            max_mint_per_window: 100,
+            max_redeem_per_window: 50,
+            rate_limit_window_slots: 10,
+            ..Default::default()
+        };
+        let mut vault_state = VaultState::default();
+
+        assert!(vault_state.record_mint(&vault_config, 60, 21).is_ok());
+        assert!(vault_state.record_mint(&vault_config, 41, 29).is_err());
+        assert!(vault_state.record_mint(&vault_config, 40, 29).is_ok());
+        assert!(vault_state.record_redeem(&vault_config, 50, 29).is_ok());
+        assert!(vault_state.record_redeem(&vault_config, 1, 29).is_err());
+
+        // next window
+        assert!(vault_state.record_mint(&vault_config, 100, 30).is_ok());
+        assert!(vault_state.record_redeem(&vault_config, 50, 30).is_ok());
+        assert_eq!(vault_state.rate_limit_window_start_slot, 30);
+    }
+
+    #[test]
+    fn legacy_vault_config_migrates_with_defaults() {
+        let legacy = LegacyVaultConfig {
+            is_initialized: true,
+            admin: Pubkey::new_unique(),
+            cooldown_duration: 3600,
+            total_staked_usdu_supply: 1_000,
+            has_initial_deposit: true,
+            ..Default::default()
+        };
+        // the first deployment sized the account for the legacy struct
+        let legacy_data = legacy.try_to_vec().unwrap();
+        assert!(legacy_data.len() + 8 < VaultConfig::SIZE);
+
+        let admin = legacy.admin;
+        let vault_config =
+            VaultConfig::from(LegacyVaultConfig::deserialize(&mut legacy_data.as_slice()).unwrap());
+        assert_eq!(vault_config.admin, admin);
+        assert_eq!(vault_config.cooldown_duration, 3600);
+        assert_eq!(vault_config.total_staked_usdu_supply, 1_000);
+        assert!(vault_config.has_initial_deposit);
+        assert_eq!(vault_config.max_mint_per_window, u64::MAX);
+        assert_eq!(vault_config.vesting_period, VESTING_PERIOD);
+        assert_eq!(
+            vault_config.max_loss_per_report_bps,
+            DEFAULT_MAX_LOSS_PER_REPORT_BPS
+        );
+        assert_eq!(
+            vault_config.action_delays,
+            [DEFAULT_ACTION_DELAY; VAULT_ACTION_TYPE_COUNT]
+        );
+        assert!(vault_config.try_to_vec().unwrap().len() + 8 <= VaultConfig::SIZE);
+    }
+
+    #[test]
+    fn ceil_previews_do_not_round_in_the_callers_favour() {
+        for decimals_offset in 0..=MAX_DECIMALS_OFFSET {
+            let mut vault_config = VaultConfig {
+                total_staked_usdu_supply: 1_000_000_007,
+                decimals_offset,
+                ..Default::default()
+            };
+            let total_shares = 999_999_991 * 10u64.pow(decimals_offset as u32);
+
+            for amount in [1, 3, 7, 1_000, 123_457, 10u64.pow(9)] {
+                assert!(
+                    vault_config.preview_mint(amount, total_shares)
+                        >= vault_config.preview_redeem(amount, total_shares)
+                );
+                assert!(
+                    vault_config.preview_withdraw(amount, total_shares)
+                        >= vault_config.preview_deposit(amount, total_shares)
+                );
+
+                // mint `amount` shares, then redeem them right away
+                let assets_in = vault_config.preview_mint(amount, total_shares);
+                vault_config.total_staked_usdu_supply += assets_in;
+                let assets_out = vault_config.preview_redeem(amount, total_shares + amount);
+                vault_config.total_staked_usdu_supply -= assets_in;
+                assert!(assets_out <= assets_in);
+
+                // withdraw `amount` assets, the burned shares buy back no more than that
+                let shares_burned = vault_config.preview_withdraw(amount, total_shares);
+                assert!(vault_config.preview_redeem(shares_burned, total_shares) >= amount);
+                assert!(vault_config.preview_deposit(amount, total_shares) <= shares_burned);
+            }
+        }
+    }
 }
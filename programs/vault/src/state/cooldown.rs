@@ -10,6 +10,34 @@ pub struct Cooldown {
     pub owner: Pubkey,
     pub bump: u8,
     pub is_initialized: bool,
+    pub index: u64,
+}
+
+/// `Cooldown` layout before tickets were indexed, its address has no index seed
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyCooldown {
+    pub cooldown_end: u64,
+    pub underlying_token_account: Pubkey,
+    pub underlying_token_mint: Pubkey,
+    pub underlying_token_amount: u64,
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+/// Hands out the index of each new cooldown ticket of a caller
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CooldownCounter {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub owner: Pubkey,
+    pub next_index: u64,
+}
+
+impl CooldownCounter {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
 }
 
 impl Cooldown {
@@ -18,4 +46,51 @@ impl Cooldown {
     pub fn is_cooldown_active(&self) -> bool {
         self.cooldown_end > Clock::get().unwrap().unix_timestamp as u64
     }
+
+    /// Moves a legacy ticket to the indexed address, keeping its amount, end time and payout account
+    pub fn from_legacy(legacy: LegacyCooldown, index: u64, bump: u8) -> Self {
+        Self {
+            cooldown_end: legacy.cooldown_end,
+            underlying_token_account: legacy.underlying_token_account,
+            underlying_token_mint: legacy.underlying_token_mint,
+            underlying_token_amount: legacy.underlying_token_amount,
+            owner: legacy.owner,
+            bump,
+            is_initialized: legacy.is_initialized,
+            index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn legacy_cooldown_keeps_its_claim() {
+        let legacy = LegacyCooldown {
+            cooldown_end: 1_700_000_000,
+            underlying_token_account: Pubkey::new_unique(),
+            underlying_token_mint: Pubkey::new_unique(),
+            underlying_token_amount: 42_000_000,
+            owner: Pubkey::new_unique(),
+            bump: 254,
+            is_initialized: true,
+        };
+        let legacy_data = legacy.try_to_vec().unwrap();
+        // the legacy account is too short to deserialize as the indexed layout
+        assert!(Cooldown::deserialize(&mut legacy_data.as_slice()).is_err());
+
+        let legacy = LegacyCooldown::deserialize(&mut legacy_data.as_slice()).unwrap();
+        let underlying_token_account = legacy.underlying_token_account;
+        let owner = legacy.owner;
+        let cooldown = Cooldown::from_legacy(legacy, 3, 251);
+        assert_eq!(cooldown.cooldown_end, 1_700_000_000);
+        assert_eq!(cooldown.underlying_token_account, underlying_token_account);
+        assert_eq!(cooldown.underlying_token_amount, 42_000_000);
+        assert_eq!(cooldown.owner, owner);
+        assert_eq!(cooldown.bump, 251);
+        assert_eq!(cooldown.index, 3);
+        assert!(cooldown.is_initialized);
+    }
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+/// Approved custodian wallet for one collateral mint, the `fund` of mint and redeem must be one of them
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Custodian {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub collateral_mint: Pubkey,
+    pub custodian: Pubkey,
+    pub is_active: bool,
+    // share of the collateral flow routed to this custodian, used by off-chain routing
+    pub routing_weight_bps: Option<u16>,
+}
+
+impl Custodian {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn check_active(&self) -> Result<()> {
+        require!(
+            self.is_initialized && self.is_active,
+            VaultError::CustodianNotActive
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,17 @@
+mod collateral;
 mod config;
 mod cooldown;
+mod custodian;
+mod order;
+mod price_feed;
+mod primary_participant;
+mod timelock;
 
+pub use collateral::*;
 pub use config::*;
 pub use cooldown::*;
+pub use custodian::*;
+pub use order::*;
+pub use price_feed::*;
+pub use primary_participant::*;
+pub use timelock::*;
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MINT_ORDER_DOMAIN, REDEEM_ORDER_DOMAIN};
+use crate::error::VaultError;
+
+pub const ORDER_NONCE_BITMAP_WORDS: usize = 16;
+
+pub const NONCES_PER_BITMAP: u64 = ORDER_NONCE_BITMAP_WORDS as u64 * 64;
+
+/// Mint order signed by the benefactor, who gives collateral, the beneficiary receives USDU
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct MintOrder {
+    pub benefactor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_amount: u64,
+    pub usdu_amount: u64,
+    pub expiry: u64,
+    pub nonce: u64,
+}
+
+/// Redeem order signed by the benefactor, who gives USDU, the beneficiary receives collateral.
+/// In `RedeemUsduWithdrawCollateral` the USDU is pulled from the `beneficiary` account and the
+/// collateral is paid to the `benefactor` account, so the roles map the other way around there.
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct RedeemOrder {
+    pub benefactor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_amount: u64,
+    pub usdu_amount: u64,
+    pub expiry: u64,
+    pub nonce: u64,
+}
+
+impl MintOrder {
+    /// Bytes the benefactor signs, domain separated from redeem orders and other programs
+    pub fn message(&self) -> Result<Vec<u8>> {
+        order_message(MINT_ORDER_DOMAIN, &self.try_to_vec()?)
+    }
+}
+
+impl RedeemOrder {
+    /// Bytes the benefactor signs, domain separated from mint orders and other programs
+    pub fn message(&self) -> Result<Vec<u8>> {
+        order_message(REDEEM_ORDER_DOMAIN, &self.try_to_vec()?)
+    }
+}
+
+fn order_message(domain: &[u8], order: &[u8]) -> Result<Vec<u8>> {
+    Ok([domain, crate::ID.as_ref(), order].concat())
+}
+
+pub fn check_order_expiry(expiry: u64) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp as u64 <= expiry,
+        VaultError::OrderExpired
+    );
+    Ok(())
+}
+
+/// One page of a benefactor's used order nonces, page `n` covers nonces
+/// `[n * NONCES_PER_BITMAP, (n + 1) * NONCES_PER_BITMAP)`
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct OrderNonceBitmap {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub owner: Pubkey,
+    pub page: u64,
+    pub bitmap: [u64; ORDER_NONCE_BITMAP_WORDS],
+}
+
+impl OrderNonceBitmap {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn page_of(nonce: u64) -> u64 {
+        nonce / NONCES_PER_BITMAP
+    }
+
+    pub fn use_nonce(&mut self, nonce: u64) -> Result<()> {
+        require!(
+            Self::page_of(nonce) == self.page,
+            VaultError::InvalidOrderNonceBitmap
+        );
+        let offset = nonce % NONCES_PER_BITMAP;
+        let word = (offset / 64) as usize;
+        let mask = 1u64 << (offset % 64);
+        require!(
+            self.bitmap[word] & mask == 0,
+            VaultError::OrderNonceAlreadyUsed
+        );
+        self.bitmap[word] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nonce_used_once() {
+        let mut bitmap = OrderNonceBitmap {
+            is_initialized: true,
+            bump: 0,
+            owner: Pubkey::default(),
+            page: 1,
+            bitmap: [0; ORDER_NONCE_BITMAP_WORDS],
+        };
+        let nonce = NONCES_PER_BITMAP + 65;
+        assert!(bitmap.use_nonce(nonce).is_ok());
+        assert!(bitmap.use_nonce(nonce).is_err());
+        assert!(bitmap.use_nonce(nonce + 1).is_ok());
+        assert_eq!(bitmap.bitmap[1], 0b110);
+        // nonce from another page
+        assert!(bitmap.use_nonce(65).is_err());
+    }
+
+    #[test]
+    fn messages_are_domain_separated() {
+        let mint = MintOrder {
+            benefactor: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            collateral_mint: Pubkey::new_unique(),
+            collateral_amount: 1,
+            usdu_amount: 1,
+            expiry: 0,
+            nonce: 0,
+        };
+        let redeem = RedeemOrder {
+            benefactor: mint.benefactor,
+            beneficiary: mint.beneficiary,
+            collateral_mint: mint.collateral_mint,
+            collateral_amount: 1,
+            usdu_amount: 1,
+            expiry: 0,
+            nonce: 0,
+        };
+        assert_ne!(mint.message().unwrap(), redeem.message().unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+/// Collateral price pushed by the price feed updater,
+/// `price` is the amount of USDU for one whole collateral token scaled by `10^decimals`
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PriceFeed {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub mint: Pubkey,
+    pub price: u64,
+    pub decimals: u8,
+    pub updated_at: u64,
+}
+
+impl PriceFeed {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn check_fresh(&self, max_price_age: u64) -> Result<()> {
+        require!(self.is_initialized, VaultError::PriceFeedNotInitialized);
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            now.saturating_sub(self.updated_at) <= max_price_age,
+            VaultError::StalePriceFeed
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+/// KYC'd counterparty allowed to be the benefactor or beneficiary of primary mint and redeem
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PrimaryParticipant {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub wallet: Pubkey,
+    pub can_mint: bool,
+    pub can_redeem: bool,
+    // unix timestamp after which the approval lapses, none means it never expires
+    pub expiry: Option<u64>,
+}
+
+impl PrimaryParticipant {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    fn check_not_expired(&self, now: u64) -> Result<()> {
+        if let Some(expiry) = self.expiry {
+            require!(now <= expiry, VaultError::ParticipantExpired);
+        }
+        Ok(())
+    }
+
+    pub fn check_mint(&self, now: u64) -> Result<()> {
+        require!(
+            self.is_initialized && self.can_mint,
+            VaultError::ParticipantMintNotAllowed
+        );
+        self.check_not_expired(now)
+    }
+
+    pub fn check_redeem(&self, now: u64) -> Result<()> {
+        require!(
+            self.is_initialized && self.can_redeem,
+            VaultError::ParticipantRedeemNotAllowed
+        );
+        self.check_not_expired(now)
+    }
+}
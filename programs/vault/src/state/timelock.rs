@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    MAX_ACTION_DELAY, MAX_COOLDOWN_DURATION, MAX_KEEPER_TIP_BPS, MIN_COOLDOWN_DURATION,
+};
+use crate::error::VaultError;
+
+/// Number of `VaultActionType` variants, one delay is configured per type
+pub const VAULT_ACTION_TYPE_COUNT: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum VaultActionType {
+    AdjustCooldown,
+    EmergencyWithdrawVaultStakePoolUsdu,
+    EmergencyWithdrawVaultSiloUsdu,
+    EmergencyWithdrawVaultUsdu,
+    EmergencyWithdrawVaultSusdu,
+    ResetTotalCooldownUsduAmount,
+    SetActionDelay,
+    SetMaxAccountingDrift,
+    SetNoCooldownMode,
+    SetKeeperTip,
+}
+
+/// Admin action that only takes effect once its delay has passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum VaultAction {
+    AdjustCooldown {
+        cooldown_duration: u64,
+    },
+    EmergencyWithdrawVaultStakePoolUsdu {
+        receiver: Pubkey,
+        amount: u64,
+    },
+    EmergencyWithdrawVaultSiloUsdu {
+        receiver: Pubkey,
+        amount: u64,
+    },
+    EmergencyWithdrawVaultUsdu {
+        receiver: Pubkey,
+        amount: u64,
+    },
+    EmergencyWithdrawVaultSusdu {
+        receiver: Pubkey,
+        amount: u64,
+    },
+    ResetTotalCooldownUsduAmount {
+        new_amount: u64,
+    },
+    SetActionDelay {
+        action_type: VaultActionType,
+        delay: u64,
+    },
+    SetMaxAccountingDrift {
+        max_accounting_drift: u64,
+    },
+    SetNoCooldownMode {
+        no_cooldown_mode: bool,
+    },
+    SetKeeperTip {
+        keeper_tip_bps: u16,
+    },
+}
+
+impl VaultAction {
+    pub fn action_type(&self) -> VaultActionType {
+        match self {
+            VaultAction::AdjustCooldown { .. } => VaultActionType::AdjustCooldown,
+            VaultAction::EmergencyWithdrawVaultStakePoolUsdu { .. } => {
+                VaultActionType::EmergencyWithdrawVaultStakePoolUsdu
+            }
+            VaultAction::EmergencyWithdrawVaultSiloUsdu { .. } => {
+                VaultActionType::EmergencyWithdrawVaultSiloUsdu
+            }
+            VaultAction::EmergencyWithdrawVaultUsdu { .. } => {
+                VaultActionType::EmergencyWithdrawVaultUsdu
+            }
+            VaultAction::EmergencyWithdrawVaultSusdu { .. } => {
+                VaultActionType::EmergencyWithdrawVaultSusdu
+            }
+            VaultAction::ResetTotalCooldownUsduAmount { .. } => {
+                VaultActionType::ResetTotalCooldownUsduAmount
+            }
+            VaultAction::SetActionDelay { .. } => VaultActionType::SetActionDelay,
+            VaultAction::SetMaxAccountingDrift { .. } => VaultActionType::SetMaxAccountingDrift,
+            VaultAction::SetNoCooldownMode { .. } => VaultActionType::SetNoCooldownMode,
+            VaultAction::SetKeeperTip { .. } => VaultActionType::SetKeeperTip,
+        }
+    }
+
+    /// Emergency withdrawals and counter resets stay with `vault_config.admin`, holders of the
+    /// `vault_admin` role can not queue or execute them
+    pub fn requires_config_admin(&self) -> bool {
+        matches!(
+            self,
+            VaultAction::EmergencyWithdrawVaultStakePoolUsdu { .. }
+                | VaultAction::EmergencyWithdrawVaultSiloUsdu { .. }
+                | VaultAction::EmergencyWithdrawVaultUsdu { .. }
+                | VaultAction::EmergencyWithdrawVaultSusdu { .. }
+                | VaultAction::ResetTotalCooldownUsduAmount { .. }
+        )
+    }
+
+    /// Checks the action parameters when it is queued, so a bad action never waits out its delay
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            VaultAction::AdjustCooldown { cooldown_duration } => {
+                require!(
+                    *cooldown_duration >= MIN_COOLDOWN_DURATION,
+                    VaultError::CooldownDurationTooShort
+                );
+                require!(
+                    *cooldown_duration <= MAX_COOLDOWN_DURATION,
+                    VaultError::CooldownDurationTooLong
+                );
+            }
+            VaultAction::EmergencyWithdrawVaultStakePoolUsdu { amount, .. }
+            | VaultAction::EmergencyWithdrawVaultSiloUsdu { amount, .. }
+            | VaultAction::EmergencyWithdrawVaultUsdu { amount, .. }
+            | VaultAction::EmergencyWithdrawVaultSusdu { amount, .. } => {
+                require!(*amount > 0, VaultError::AmountMustBeGreaterThanZero);
+            }
+            VaultAction::ResetTotalCooldownUsduAmount { .. }
+            | VaultAction::SetMaxAccountingDrift { .. }
+            | VaultAction::SetNoCooldownMode { .. } => {}
+            VaultAction::SetActionDelay { delay, .. } => {
+                require!(*delay <= MAX_ACTION_DELAY, VaultError::ActionDelayTooLong);
+            }
+            VaultAction::SetKeeperTip { keeper_tip_bps } => {
+                require!(
+                    *keeper_tip_bps <= MAX_KEEPER_TIP_BPS,
+                    VaultError::KeeperTipTooHigh
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PendingAction {
+    pub is_initialized: bool,
+    pub bump: u8,
+
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: VaultAction,
+    pub queued_at: u64,
+    pub eta: u64,
+}
+
+impl PendingAction {
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_ready(&self, now: u64) -> bool {
+        now >= self.eta
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn action_type_indexes_cover_all_delays() {
+        let action = VaultAction::SetKeeperTip { keeper_tip_bps: 0 };
+        assert_eq!(action.action_type() as usize, VAULT_ACTION_TYPE_COUNT - 1);
+        assert!(VaultAction::EmergencyWithdrawVaultUsdu {
+            receiver: Pubkey::default(),
+            amount: 0,
+        }
+        .validate()
+        .is_err());
+        assert!(VaultAction::SetActionDelay {
+            action_type: VaultActionType::AdjustCooldown,
+            delay: MAX_ACTION_DELAY + 1,
+        }
+        .validate()
+        .is_err());
+        assert!(VaultAction::SetKeeperTip {
+            keeper_tip_bps: MAX_KEEPER_TIP_BPS + 1,
+        }
+        .validate()
+        .is_err());
+        assert!(VaultAction::ResetTotalCooldownUsduAmount { new_amount: 0 }.requires_config_admin());
+        assert!(!VaultAction::AdjustCooldown {
+            cooldown_duration: MIN_COOLDOWN_DURATION,
+        }
+        .requires_config_admin());
+    }
+}
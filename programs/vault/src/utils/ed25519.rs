@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::VaultError;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+/// The ed25519 program instruction must directly precede the current instruction.
+/// The runtime has already verified its signature, so only the signed key and message are compared.
+pub fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, VaultError::InvalidEd25519Instruction);
+    let instruction =
+        load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        VaultError::InvalidEd25519Instruction
+    );
+    check_ed25519_data(&instruction.data, signer, message)
+}
+
+fn check_ed25519_data(data: &[u8], signer: &Pubkey, message: &[u8]) -> Result<()> {
+    // exactly one signature whose offsets all point into this instruction's data
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        VaultError::InvalidEd25519Instruction
+    );
+    let read_u16 = |index: usize| {
+        let start = SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+    require!(
+        signature_instruction_index == u16::MAX
+            && public_key_instruction_index == u16::MAX
+            && message_instruction_index == u16::MAX,
+        VaultError::InvalidEd25519Instruction
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + PUBKEY_SIZE)
+        .ok_or(VaultError::InvalidEd25519Instruction)?;
+    require!(
+        public_key == signer.as_ref(),
+        VaultError::OrderSignerMismatch
+    );
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(VaultError::InvalidEd25519Instruction)?;
+    require!(signed_message == message, VaultError::OrderMessageMismatch);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // same layout as solana_sdk::ed25519_instruction::new_ed25519_instruction
+    fn ed25519_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let header = (SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE) as u16;
+        let public_key_offset = header;
+        let signature_offset = public_key_offset + PUBKEY_SIZE as u16;
+        let message_data_offset = signature_offset + 64;
+        let mut data = vec![1u8, 0];
+        for value in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_data_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn accepts_matching_signer_and_message() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"order", u16::MAX);
+        assert!(check_ed25519_data(&data, &signer, b"order").is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatches() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"order", u16::MAX);
+        assert!(check_ed25519_data(&data, &Pubkey::new_unique(), b"order").is_err());
+        assert!(check_ed25519_data(&data, &signer, b"other").is_err());
+
+        // offsets pointing at another instruction are not trusted
+        let data = ed25519_data(&signer, b"order", 0);
+        assert!(check_ed25519_data(&data, &signer, b"order").is_err());
+    }
+}
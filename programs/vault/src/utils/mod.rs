@@ -4,3 +4,9 @@ pub use token::*;
 
 mod role;
 pub use role::*;
+
+mod oracle;
+pub use oracle::*;
+
+mod ed25519;
+pub use ed25519::*;
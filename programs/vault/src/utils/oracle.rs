@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+use crate::error::VaultError;
+
+/// USDU amount the price feed implies for `collateral_amount`, normalised for decimals
+pub fn collateral_to_usdu(
+    collateral_amount: u64,
+    collateral_decimals: u8,
+    usdu_decimals: u8,
+    price: u64,
+    price_decimals: u8,
+) -> Result<u128> {
+    let numerator = (collateral_amount as u128)
+        .checked_mul(price as u128)
+        .and_then(|v| v.checked_mul(10u128.checked_pow(usdu_decimals.into())?))
+        .ok_or(VaultError::MathOverflow)?;
+    let denominator = 10u128
+        .checked_pow(u32::from(collateral_decimals) + u32::from(price_decimals))
+        .ok_or(VaultError::MathOverflow)?;
+    Ok(numerator / denominator)
+}
+
+/// Reject when `usdu_amount` is more than `max_deviation_bps` away from the oracle value
+pub fn check_price_deviation(
+    collateral_amount: u64,
+    collateral_decimals: u8,
+    usdu_amount: u64,
+    usdu_decimals: u8,
+    price: u64,
+    price_decimals: u8,
+    max_deviation_bps: u16,
+) -> Result<()> {
+    let expected = collateral_to_usdu(
+        collateral_amount,
+        collateral_decimals,
+        usdu_decimals,
+        price,
+        price_decimals,
+    )?;
+    require!(expected > 0, VaultError::InvalidPrice);
+
+    let actual = usdu_amount as u128;
+    let diff = actual.abs_diff(expected);
+    let max_diff = expected
+        .checked_mul(max_deviation_bps as u128)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(
+        diff.checked_mul(BASIS_POINTS_DENOMINATOR as u128)
+            .ok_or(VaultError::MathOverflow)?
+            <= max_diff,
+        VaultError::PriceDeviationTooLarge
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalises_decimals() {
+        // 1 collateral (9 decimals) at 1.0 (8 price decimals) is 1 USDU (6 decimals)
+        let usdu = collateral_to_usdu(1_000_000_000, 9, 6, 100_000_000, 8).unwrap();
+        assert_eq!(usdu, 1_000_000);
+
+        // 2.5 collateral (6 decimals) at 0.998 (3 price decimals)
+        let usdu = collateral_to_usdu(2_500_000, 6, 6, 998, 3).unwrap();
+        assert_eq!(usdu, 2_495_000);
+    }
+
+    #[test]
+    fn deviation_band() {
+        // 1000 collateral at 1.0, 50 bps band
+        assert!(check_price_deviation(1_000_000_000, 6, 1_000_000_000, 6, 1, 0, 50).is_ok());
+        assert!(check_price_deviation(1_000_000_000, 6, 1_005_000_000, 6, 1, 0, 50).is_ok());
+        assert!(check_price_deviation(1_000_000_000, 6, 995_000_000, 6, 1, 0, 50).is_ok());
+        assert!(check_price_deviation(1_000_000_000, 6, 1_005_000_001, 6, 1, 0, 50).is_err());
+        assert!(check_price_deviation(1_000_000_000, 6, 994_999_999, 6, 1, 0, 50).is_err());
+    }
+
+    #[test]
+    fn zero_price_rejected() {
+        assert!(check_price_deviation(1_000_000, 6, 1_000_000, 6, 0, 0, 10_000).is_err());
+    }
+}
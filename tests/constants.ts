@@ -2,14 +2,32 @@
 export const vaultStateSeed = "vault-state";
 export const vaultConfigSeed = "vault-config";
 export const vaultCooldownSeed = "vault-cooldown";
+export const vaultCooldownCounterSeed = "vault-cooldown-counter";
+export const vaultDeadSharesSeed = "vault-dead-shares";
+export const vaultPendingActionSeed = "vault-pending-action";
+export const vaultOrderNonceSeed = "vault-order-nonce";
+export const mintOrderDomain = "usdu-mint-order";
+export const redeemOrderDomain = "usdu-redeem-order";
 export const vaultSusduTokenAccountSeed = "vault-susdu-token-approval";
 export const vaultUsduTokenAccountSeed = "vault-usdu-approval";
 export const vaultStakePoolUsduTokenAccountSeed = "vault-stake-pool-usdu";
 export const vaultSiloUsduTokenAccountSeed = "vault-silo-usdu";
+export const vaultCollateralConfigSeed = "vault-collateral-config";
+export const vaultPriceFeedSeed = "vault-price-feed";
+export const vaultCustodianSeed = "vault-custodian";
+export const vaultPrimaryParticipantSeed = "vault-primary-participant";
 export const blacklistHookConfigSeed = "blacklist-hook-config";
 export const blacklistHookExtraAccountMetaListSeed = "extra-account-metas";
 export const blacklistEntrySeed = "blacklist-entry";
 
+/// vault pause flags
+export const PAUSE_MINT = 1 << 0;
+export const PAUSE_REDEEM = 1 << 1;
+export const PAUSE_STAKE = 1 << 2;
+export const PAUSE_UNSTAKE = 1 << 3;
+export const PAUSE_WITHDRAW = 1 << 4;
+export const PAUSE_REWARD = 1 << 5;
+
 /// usdu seeds
 export const usduConfigSeed = "usdu-config";
 export const usduSeed = "usdu-spl-token";
@@ -21,6 +39,8 @@ export const susduSeed = "susdu-spl-token";
 /// access registry seeds
 export const accessRegistrySeed = "access-registry";
 export const accessRoleSeed = "access-role";
+export const multisigSeed = "multisig";
+export const multisigProposalSeed = "multisig-proposal";
 
 export type RoleType =
   | { guardianAdmin: {} }
@@ -34,7 +54,11 @@ export type RoleType =
   | { usduStaker: {} }
   | { usduUnstaker: {} }
   | { vaultAdmin: {} }
-  | { rewardDistributor: {} };
+  | { rewardDistributor: {} }
+  | { priceFeedUpdater: {} }
+  | { participantManager: {} }
+  | { pauser: {} }
+  | { lossReporter: {} };
 
 export function getRole(role: string): RoleType {
   switch (role) {
@@ -64,6 +88,16 @@ export function getRole(role: string): RoleType {
       return { rewardDistributor: {} };
     case "distribute_rewarder":
       return { rewardDistributor: {} };
+    case "price_feed_updater":
+      return { priceFeedUpdater: {} };
+    case "participant_manager":
+      return { participantManager: {} };
+    case "pauser":
+      return { pauser: {} };
+    case "loss_reporter":
+      return { lossReporter: {} };
+    case "action_canceller":
+      return { actionCanceller: {} };
     default:
       throw new Error(`Invalid role: ${role}`);
   }
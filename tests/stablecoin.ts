@@ -11,6 +11,7 @@ import {
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAccount,
+  getMint,
   TOKEN_2022_PROGRAM_ID,
   getOrCreateAssociatedTokenAccount,
   createApproveInstruction,
@@ -31,13 +32,20 @@ import {
   vaultStakePoolUsduTokenAccountSeed,
   vaultSiloUsduTokenAccountSeed,
   vaultStateSeed,
-  vaultCooldownSeed,
   vaultSusduTokenAccountSeed,
   vaultUsduTokenAccountSeed,
   usduConfigSeed,
   susduConfigSeed,
   blacklistHookExtraAccountMetaListSeed,
   blacklistHookConfigSeed,
+  vaultCollateralConfigSeed,
+  vaultPriceFeedSeed,
+  vaultCustodianSeed,
+  vaultPrimaryParticipantSeed,
+  getRole,
+  accessRoleSeed,
+  roleToBytes,
+  PAUSE_STAKE,
 } from "./constants";
 import {
   InitGuardianAccessRegistry,
@@ -48,16 +56,55 @@ import {
   InitVaultState,
   AirdropSol,
   CreateMintToken,
+  AddCollateral,
+  UpdatePriceFeed,
+  AddCustodian,
+  SetPrimaryParticipant,
   DepositCollateralAndMintUsdu,
   RedeemUsduAndWithdrawCollateral,
   StakeUsduMintSusdu,
   UnstakeSusdu,
   WithdrawUsdu,
+  CrankWithdrawUsdu,
+  CancelCooldown,
+  CloseCooldown,
+  SetCooldownReceiver,
+  SetVestingPeriod,
+  SetRewardFee,
+  SetDecimalsOffset,
+  getDeadSharesOwnerPda,
   DistributeUsduReward,
+  QueueAction,
+  CancelAction,
+  ExecuteAction,
+  SetActionDelay,
+  expectError,
+  getTokenBalance,
+  MintUsduWithOrder,
+  MintSusduExact,
+  UnstakeUsduExact,
+  UnstakeSusduDirect,
+  SetNoCooldownMode,
+  ReportLoss,
+  Pause,
+  Unpause,
+  RedeemUsduWithOrder,
+  SyncVaultAccounting,
   RedistributeLockedSusdu,
   InitializeBlacklistHook,
   AddToBlacklist,
   getBlacklistEntryPda,
+  getCooldownPda,
+  SetMultisig,
+  CreateProposal,
+  ApproveProposal,
+  CloseProposal,
+  ExecuteAssignRole,
+  ExecuteRevokeRole,
+  ExecuteProposeNewAdmin,
+  ExecuteUpdateSigners,
+  AcceptAdminTransfer,
+  getNextCooldownIndex,
 } from "./utils";
 import { assert } from "chai";
 import {
@@ -99,6 +146,8 @@ describe("Stablecoin Preparation", () => {
   let susduMinter: PublicKey;
   let susduRedeemer: PublicKey;
   let vaultAdmin: PublicKey;
+  let priceFeedUpdater: PublicKey;
+  let participantManager: PublicKey;
   let susduRedistributor: PublicKey;
   let extraAccountMetaList: PublicKey;
   let blacklistHookConfig: PublicKey;
@@ -285,6 +334,22 @@ describe("Stablecoin Preparation", () => {
       admin.publicKey,
       "vault_admin"
     );
+    // assign price_feed_updater role to admin
+    priceFeedUpdater = await AssignRole(
+      guardianProgram,
+      accessRegistry,
+      admin,
+      admin.publicKey,
+      "price_feed_updater"
+    );
+    // assign participant_manager role to admin
+    participantManager = await AssignRole(
+      guardianProgram,
+      accessRegistry,
+      admin,
+      admin.publicKey,
+      "participant_manager"
+    );
   });
 
   it("get usdu token and susdu token", async () => {
@@ -315,6 +380,11 @@ describe("Stablecoin Preparation", () => {
     let benefactor = Keypair.fromSecretKey(Uint8Array.from(benefactorBytes));
     let fund = Keypair.fromSecretKey(Uint8Array.from(fundBytes));
     let beneficiary = Keypair.fromSecretKey(Uint8Array.from(beneficiaryBytes));
+    let collateralConfig: PublicKey;
+    let priceFeed: PublicKey;
+    let custodian: PublicKey;
+    let benefactorParticipant: PublicKey;
+    let beneficiaryParticipant: PublicKey;
 
     let beneficiaryUsduTokenAccount: Account;
     let beneficiaryCollateralTokenAccount: Account;
@@ -349,6 +419,87 @@ describe("Stablecoin Preparation", () => {
         6
       );
       console.log(`mintToken: ${mintToken.publicKey}`);
+      [collateralConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from(vaultCollateralConfigSeed), mintToken.publicKey.toBuffer()],
+        vaultProgram.programId
+      );
+      await AddCollateral(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        mintToken.publicKey,
+        collateralConfig,
+        100_000_000_000_000,
+        100_000_000_000_000
+      );
+      [priceFeed] = PublicKey.findProgramAddressSync(
+        [Buffer.from(vaultPriceFeedSeed), mintToken.publicKey.toBuffer()],
+        vaultProgram.programId
+      );
+      // 1 collateral = 1.00000000 usdu
+      await UpdatePriceFeed(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        priceFeedUpdater,
+        collateralConfig,
+        priceFeed,
+        100_000_000,
+        8
+      );
+      [custodian] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(vaultCustodianSeed),
+          mintToken.publicKey.toBuffer(),
+          fund.publicKey.toBuffer(),
+        ],
+        vaultProgram.programId
+      );
+      await AddCustodian(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        collateralConfig,
+        fund.publicKey,
+        custodian,
+        null
+      );
+      [benefactorParticipant] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(vaultPrimaryParticipantSeed),
+          benefactor.publicKey.toBuffer(),
+        ],
+        vaultProgram.programId
+      );
+      [beneficiaryParticipant] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(vaultPrimaryParticipantSeed),
+          beneficiary.publicKey.toBuffer(),
+        ],
+        vaultProgram.programId
+      );
+      for (const [wallet, primaryParticipant] of [
+        [benefactor.publicKey, benefactorParticipant],
+        [beneficiary.publicKey, beneficiaryParticipant],
+      ]) {
+        await SetPrimaryParticipant(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          accessRegistry,
+          participantManager,
+          wallet,
+          primaryParticipant,
+          true,
+          true,
+          null
+        );
+      }
       beneficiaryUsduTokenAccount = await getOrCreateAssociatedTokenAccount(
         connection,
         beneficiary,
@@ -502,16 +653,22 @@ describe("Stablecoin Preparation", () => {
         usduProgram,
         admin,
         vaultConfig,
+        vaultState,
         usduConfig,
         accessRegistry,
         usduMinter,
         collateralDepositor,
         mintToken.publicKey,
+        collateralConfig,
+        priceFeed,
         usduMintToken,
         benefactor,
         beneficiary,
         fund,
-        2200_000_000,
+        custodian,
+        benefactorParticipant,
+        beneficiaryParticipant,
+        2000_000_000,
         2000_000_000,
         benefactorCollateralTokenAccount.address,
         beneficiaryUsduTokenAccount.address,
@@ -587,11 +744,16 @@ describe("Stablecoin Preparation", () => {
         usduRedeemer,
         collateralWithdrawer,
         mintToken.publicKey,
+        collateralConfig,
+        priceFeed,
         usduMintToken,
         benefactor,
         beneficiary,
         fund,
-        20_000_000,
+        custodian,
+        benefactorParticipant,
+        beneficiaryParticipant,
+        10_000_000,
         10_000_000,
         beneficiaryUsduTokenAccount.address,
         fundCollateralTokenAccount.address,
@@ -612,6 +774,126 @@ describe("Stablecoin Preparation", () => {
       );
     });
 
+    it("fill signed mint and redeem orders once", async () => {
+      const expiry = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+      const mintOrder = {
+        benefactor: benefactor.publicKey,
+        beneficiary: beneficiary.publicKey,
+        collateralMint: mintToken.publicKey,
+        collateralAmount: new BN(5_000_000),
+        usduAmount: new BN(5_000_000),
+        expiry,
+        nonce: new BN(1),
+      };
+      const mintOrderFill = (payer: Keypair) =>
+        MintUsduWithOrder(
+          vaultProgram,
+          usduProgram,
+          admin,
+          payer,
+          vaultConfig,
+          vaultState,
+          usduConfig,
+          accessRegistry,
+          usduMinter,
+          collateralDepositor,
+          collateralConfig,
+          priceFeed,
+          usduMintToken,
+          fund.publicKey,
+          custodian,
+          benefactorParticipant,
+          beneficiaryParticipant,
+          benefactorCollateralTokenAccount.address,
+          beneficiaryUsduTokenAccount.address,
+          fundCollateralTokenAccount.address,
+          mintOrder,
+          benefactor
+        );
+
+      const usduBefore = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      await mintOrderFill(admin);
+      const usduAfter = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      assert.equal(
+        usduAfter.sub(usduBefore).toString(),
+        mintOrder.usduAmount.toString()
+      );
+      // a different payer makes a new transaction, the nonce alone must stop the replay
+      await expectError(
+        mintOrderFill(fund),
+        vaultProgram,
+        "OrderNonceAlreadyUsed"
+      );
+
+      // the USDU holder signs the redeem order, the collateral goes to the benefactor
+      const redeemOrder = {
+        benefactor: beneficiary.publicKey,
+        beneficiary: benefactor.publicKey,
+        collateralMint: mintToken.publicKey,
+        collateralAmount: new BN(5_000_000),
+        usduAmount: new BN(5_000_000),
+        expiry,
+        nonce: new BN(1),
+      };
+      const redeemOrderFill = (payer: Keypair) =>
+        RedeemUsduWithOrder(
+          vaultProgram,
+          usduProgram,
+          admin,
+          payer,
+          vaultConfig,
+          vaultState,
+          usduConfig,
+          accessRegistry,
+          usduRedeemer,
+          collateralWithdrawer,
+          collateralConfig,
+          priceFeed,
+          usduMintToken,
+          fund.publicKey,
+          custodian,
+          benefactorParticipant,
+          beneficiaryParticipant,
+          beneficiaryUsduTokenAccount.address,
+          fundCollateralTokenAccount.address,
+          vaultUsduTokenAccount,
+          benefactorCollateralTokenAccount.address,
+          redeemOrder,
+          beneficiary
+        );
+
+      const collateralBefore = await getTokenBalance(
+        connection,
+        benefactorCollateralTokenAccount.address
+      );
+      await redeemOrderFill(admin);
+      const collateralAfter = await getTokenBalance(
+        connection,
+        benefactorCollateralTokenAccount.address
+      );
+      assert.equal(
+        collateralAfter.sub(collateralBefore).toString(),
+        redeemOrder.collateralAmount.toString()
+      );
+      assert.equal(
+        (
+          await getTokenBalance(connection, beneficiaryUsduTokenAccount.address)
+        ).toString(),
+        usduAfter.sub(redeemOrder.usduAmount).toString()
+      );
+      await expectError(
+        redeemOrderFill(fund),
+        vaultProgram,
+        "OrderNonceAlreadyUsed"
+      );
+    });
+
     it("stake usdu and mint susdu", async () => {
       const caller = beneficiary;
       await StakeUsduMintSusdu(
@@ -644,19 +926,120 @@ describe("Stablecoin Preparation", () => {
         `After stake usdu, susduConfigAccount TotalSupply: ${susduConfigAccount.totalSupply}`
       );
     });
+    it("pauser pauses staking and only the admin lifts it", async () => {
+      const pauser = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        fund.publicKey,
+        "pauser"
+      );
+      await Pause(
+        vaultProgram,
+        fund,
+        vaultConfig,
+        accessRegistry,
+        pauser,
+        PAUSE_STAKE
+      );
+      let vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.pausedFlags, PAUSE_STAKE);
+
+      const stake = () =>
+        StakeUsduMintSusdu(
+          vaultProgram,
+          susduProgram,
+          beneficiary,
+          susduReceiver,
+          susduReceiverSusduTokenAccount.address,
+          beneficiaryUsduTokenAccount.address,
+          accessRegistry,
+          vaultStakePoolUsduTokenAccount,
+          susduMinter,
+          usduMintToken,
+          susduMintToken,
+          vaultState,
+          vaultConfig,
+          susduConfig,
+          1_000_000
+        );
+      await expectError(stake(), vaultProgram, "OperationPaused");
+
+      // the pauser can not lift the flag it set
+      await expectError(
+        Unpause(vaultProgram, fund, vaultConfig, PAUSE_STAKE),
+        vaultProgram,
+        "OnlyAdminCanUnpause"
+      );
+
+      await Unpause(vaultProgram, admin, vaultConfig, PAUSE_STAKE);
+      vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.pausedFlags, 0);
+      await stake();
+    });
+    it("initial dead shares are held by the unowned PDA", async () => {
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.isTrue(vaultConfigAccount.hasInitialDeposit);
+      const deadSharesTokenAccount = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(
+          susduMintToken,
+          getDeadSharesOwnerPda(vaultProgram),
+          true,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      // MIN_SHARES scaled by the decimals offset
+      assert.equal(
+        deadSharesTokenAccount.amount.toString(),
+        (
+          BigInt(1_000_000) *
+          BigInt(10) ** BigInt(vaultConfigAccount.decimalsOffset)
+        ).toString()
+      );
+    });
+    it("decimals offset is locked after the initial deposit", async () => {
+      const offsetBefore = (
+        await vaultProgram.account.vaultConfig.fetch(vaultConfig)
+      ).decimalsOffset;
+      let refused = false;
+      try {
+        await SetDecimalsOffset(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          accessRegistry,
+          vaultAdmin,
+          (offsetBefore + 1) % 4
+        );
+      } catch (error) {
+        refused = true;
+      }
+      assert.isTrue(refused, "shares already exist at the current offset");
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.decimalsOffset, offsetBefore);
+    });
     it("unstake susdu and ready to wait cooldown", async () => {
       const caller = susduReceiver;
       const callerSusduTokenAccount = susduReceiverSusduTokenAccount.address;
       const receiver = beneficiary;
       const receiverUsduTokenAccount = beneficiaryUsduTokenAccount.address;
-      const [cooldown] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(vaultCooldownSeed),
-          Buffer.from(usduMintToken.toBuffer()),
-          Buffer.from(receiver.publicKey.toBuffer()),
-          Buffer.from(caller.publicKey.toBuffer()),
-        ],
-        vaultProgram.programId
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        await getNextCooldownIndex(vaultProgram, caller.publicKey)
       );
       let sourceTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, caller.publicKey);
       let destinationTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, vaultConfig);
@@ -708,14 +1091,15 @@ describe("Stablecoin Preparation", () => {
       const caller = susduReceiver;
       const receiver = beneficiary;
       const receiverUsduTokenAccount = beneficiaryUsduTokenAccount.address;
-      const [cooldown] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(vaultCooldownSeed),
-          Buffer.from(usduMintToken.toBuffer()),
-          Buffer.from(receiver.publicKey.toBuffer()),
-          Buffer.from(caller.publicKey.toBuffer()),
-        ],
-        vaultProgram.programId
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        0
+      );
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
       );
       await WithdrawUsdu(
         vaultProgram,
@@ -726,7 +1110,247 @@ describe("Stablecoin Preparation", () => {
         receiverUsduTokenAccount,
         vaultSiloUsduTokenAccount,
         cooldown,
-        usduMintToken
+        usduMintToken,
+        cooldownAccount.underlyingTokenAmount.toNumber()
+      );
+      // the emptied cooldown account is closed and its rent refunded
+      assert.isNull(await vaultProgram.account.cooldown.fetchNullable(cooldown));
+    });
+    it("keeper cranks a matured cooldown", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const receiverUsduTokenAccount = beneficiaryUsduTokenAccount.address;
+      const keeper = admin;
+      const keeperUsduTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        keeper,
+        usduMintToken,
+        keeper.publicKey,
+        true,
+        "confirmed",
+        {
+          skipPreflight: true,
+          commitment: "confirmed",
+        },
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      // 1% of the payout goes to the keeper
+      // testnet builds queue actions with no delay
+      const keeperTipAction = await QueueAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        { setKeeperTip: { keeperTipBps: 100 } }
+      );
+      await ExecuteAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        keeperTipAction,
+        accessRegistry,
+        vaultAdmin
+      );
+
+      // the cooldown duration is 0, so the ticket matures right away
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        await getNextCooldownIndex(vaultProgram, caller.publicKey)
+      );
+      await UnstakeSusdu(
+        vaultProgram,
+        susduProgram,
+        blacklistHookProgram,
+        caller,
+        susduReceiverSusduTokenAccount.address,
+        receiver.publicKey,
+        receiverUsduTokenAccount,
+        susduConfig,
+        vaultConfig,
+        vaultState,
+        vaultSusduTokenAccount,
+        cooldown,
+        accessRegistry,
+        susduRedeemer,
+        susduMintToken,
+        usduMintToken,
+        vaultStakePoolUsduTokenAccount,
+        vaultSiloUsduTokenAccount,
+        3_000_000,
+        extraAccountMetaList,
+        getBlacklistEntryPda(blacklistHookProgram, caller.publicKey),
+        getBlacklistEntryPda(blacklistHookProgram, vaultConfig)
+      );
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
+      );
+      const usduAmount = cooldownAccount.underlyingTokenAmount;
+      const keeperTip = usduAmount.muln(100).divn(10_000);
+
+      const receiverBefore = await getTokenBalance(
+        connection,
+        receiverUsduTokenAccount
+      );
+      const keeperBefore = await getTokenBalance(
+        connection,
+        keeperUsduTokenAccount.address
+      );
+      const ownerLamportsBefore = await connection.getBalance(
+        caller.publicKey,
+        "confirmed"
+      );
+      const cooldownRent = await connection.getBalance(cooldown, "confirmed");
+
+      await CrankWithdrawUsdu(
+        vaultProgram,
+        keeper,
+        keeperUsduTokenAccount.address,
+        vaultConfig,
+        vaultState,
+        vaultSiloUsduTokenAccount,
+        usduMintToken,
+        [
+          {
+            cooldown,
+            receiverUsduTokenAccount,
+            owner: caller.publicKey,
+          },
+        ]
+      );
+
+      const receiverAfter = await getTokenBalance(
+        connection,
+        receiverUsduTokenAccount
+      );
+      const keeperAfter = await getTokenBalance(
+        connection,
+        keeperUsduTokenAccount.address
+      );
+      assert.equal(
+        receiverAfter.sub(receiverBefore).toString(),
+        usduAmount.sub(keeperTip).toString()
+      );
+      assert.equal(
+        keeperAfter.sub(keeperBefore).toString(),
+        keeperTip.toString()
+      );
+      // the ticket is closed and its rent refunded to the owner
+      assert.isNull(await vaultProgram.account.cooldown.fetchNullable(cooldown));
+      assert.equal(
+        await connection.getBalance(caller.publicKey, "confirmed"),
+        ownerLamportsBefore + cooldownRent
+      );
+    });
+    it("check vault invariants", async () => {
+      await vaultProgram.methods
+        .checkInvariants()
+        .accountsStrict({
+          vaultConfig,
+          vaultState,
+          vaultStakePoolUsduTokenAccount,
+          vaultSiloUsduTokenAccount,
+          usduConfig,
+          susduConfig,
+          usduToken: usduMintToken,
+          susduToken: susduMintToken,
+        })
+        .simulate();
+    });
+    it("check vault invariants fails once the cooldown counter outgrows the silo", async () => {
+      const resetTotalCooldownUsduAmount = async (newAmount: BN) => {
+        const pendingAction = await QueueAction(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          accessRegistry,
+          vaultAdmin,
+          { resetTotalCooldownUsduAmount: { newAmount } }
+        );
+        await ExecuteAction(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          vaultState,
+          pendingAction,
+          accessRegistry,
+          vaultAdmin
+        );
+      };
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const siloBalance = await getTokenBalance(
+        connection,
+        vaultSiloUsduTokenAccount
+      );
+
+      await resetTotalCooldownUsduAmount(siloBalance.addn(1));
+      await expectError(
+        vaultProgram.methods
+          .checkInvariants()
+          .accountsStrict({
+            vaultConfig,
+            vaultState,
+            vaultStakePoolUsduTokenAccount,
+            vaultSiloUsduTokenAccount,
+            usduConfig,
+            susduConfig,
+            usduToken: usduMintToken,
+            susduToken: susduMintToken,
+          })
+          .simulate(),
+        vaultProgram,
+        "InsufficientUsduForCooldowns"
+      );
+
+      await resetTotalCooldownUsduAmount(
+        vaultConfigAccount.totalCooldownUsduAmount
+      );
+    });
+    it("sync vault accounting within the configured drift", async () => {
+      let vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.maxAccountingDrift.toNumber(), 10_000_000);
+
+      await SyncVaultAccounting(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        vaultStakePoolUsduTokenAccount,
+        vaultSiloUsduTokenAccount,
+        accessRegistry,
+        vaultAdmin
+      );
+      vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const stakePool = await getAccount(
+        connection,
+        vaultStakePoolUsduTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const silo = await getAccount(
+        connection,
+        vaultSiloUsduTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        vaultConfigAccount.totalStakedUsduSupply.toString(),
+        stakePool.amount.toString()
+      );
+      assert.equal(
+        vaultConfigAccount.totalCooldownUsduAmount.toString(),
+        silo.amount.toString()
       );
     });
     it("unstake susdu and ready to wait cooldown", async () => {
@@ -734,14 +1358,12 @@ describe("Stablecoin Preparation", () => {
       const callerSusduTokenAccount = susduReceiverSusduTokenAccount.address;
       const receiver = beneficiary;
       const receiverUsduTokenAccount = beneficiaryUsduTokenAccount.address;
-      const [cooldown] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(vaultCooldownSeed),
-          Buffer.from(usduMintToken.toBuffer()),
-          Buffer.from(receiver.publicKey.toBuffer()),
-          Buffer.from(caller.publicKey.toBuffer()),
-        ],
-        vaultProgram.programId
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        await getNextCooldownIndex(vaultProgram, caller.publicKey)
       );
       let sourceTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, caller.publicKey);
       let destinationTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, vaultConfig);
@@ -788,9 +1410,749 @@ describe("Stablecoin Preparation", () => {
         `After unstake susdu, cooldownAccount.underlyingTokenAmount: ${cooldownAccount.underlyingTokenAmount}`
       );
     });
-    it("stake usdu and mint susdu again", async () => {
-      const caller = beneficiary;
-      await StakeUsduMintSusdu(
+    it("cancel part of the cooldown and restake", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        (await getNextCooldownIndex(vaultProgram, caller.publicKey)) - 1
+      );
+      const before = await vaultProgram.account.cooldown.fetch(cooldown);
+      await CancelCooldown(
+        vaultProgram,
+        susduProgram,
+        caller,
+        susduReceiverSusduTokenAccount.address,
+        receiver.publicKey,
+        cooldown,
+        vaultConfig,
+        vaultState,
+        vaultSiloUsduTokenAccount,
+        vaultStakePoolUsduTokenAccount,
+        accessRegistry,
+        susduMinter,
+        susduConfig,
+        susduMintToken,
+        usduMintToken,
+        before.underlyingTokenAmount.divn(2).toNumber(),
+      );
+      const after = await vaultProgram.account.cooldown.fetch(cooldown);
+      console.log(
+        `After cancel cooldown, cooldownAccount.underlyingTokenAmount: ${after.underlyingTokenAmount}`
+      );
+    });
+    it("close cooldown refuses a ticket with usdu left", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        (await getNextCooldownIndex(vaultProgram, caller.publicKey)) - 1
+      );
+      const before = await vaultProgram.account.cooldown.fetch(cooldown);
+      assert.isTrue(before.underlyingTokenAmount.gtn(0));
+
+      let refused = false;
+      try {
+        await CloseCooldown(
+          vaultProgram,
+          caller,
+          receiver.publicKey,
+          cooldown,
+          vaultConfig
+        );
+      } catch (error) {
+        refused = true;
+      }
+      assert.isTrue(refused, "a cooldown holding usdu cannot be closed");
+      const after = await vaultProgram.account.cooldown.fetch(cooldown);
+      assert.equal(
+        after.underlyingTokenAmount.toString(),
+        before.underlyingTokenAmount.toString()
+      );
+    });
+    it("withdraw usdu to a re-pointed receiver", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const newReceiverUsduTokenAccount = benefactorUsduTokenAccount.address;
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        (await getNextCooldownIndex(vaultProgram, caller.publicKey)) - 1
+      );
+      await SetCooldownReceiver(
+        vaultProgram,
+        blacklistHookProgram,
+        caller,
+        receiver.publicKey,
+        cooldown,
+        benefactor.publicKey,
+        newReceiverUsduTokenAccount,
+        usduMintToken
+      );
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
+      );
+      assert.ok(
+        cooldownAccount.underlyingTokenAccount.equals(
+          newReceiverUsduTokenAccount
+        )
+      );
+
+      const newReceiverBefore = await getAccount(
+        connection,
+        newReceiverUsduTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const oldReceiverBefore = await getAccount(
+        connection,
+        beneficiaryUsduTokenAccount.address,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      // the cooldown seeds keep the original receiver
+      await WithdrawUsdu(
+        vaultProgram,
+        caller,
+        receiver.publicKey,
+        vaultConfig,
+        vaultState,
+        newReceiverUsduTokenAccount,
+        vaultSiloUsduTokenAccount,
+        cooldown,
+        usduMintToken,
+        cooldownAccount.underlyingTokenAmount.toNumber()
+      );
+      const newReceiverAfter = await getAccount(
+        connection,
+        newReceiverUsduTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const oldReceiverAfter = await getAccount(
+        connection,
+        beneficiaryUsduTokenAccount.address,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        (newReceiverAfter.amount - newReceiverBefore.amount).toString(),
+        cooldownAccount.underlyingTokenAmount.toString()
+      );
+      assert.equal(oldReceiverAfter.amount, oldReceiverBefore.amount);
+      assert.isNull(await vaultProgram.account.cooldown.fetchNullable(cooldown));
+    });
+    it("stake and unstake honour their slippage bounds", async () => {
+      const viewAccounts = {
+        vaultConfig,
+        susduConfig,
+        susduToken: susduMintToken,
+      };
+      const stake = (minSusduOut: number) =>
+        StakeUsduMintSusdu(
+          vaultProgram,
+          susduProgram,
+          beneficiary,
+          susduReceiver,
+          susduReceiverSusduTokenAccount.address,
+          beneficiaryUsduTokenAccount.address,
+          accessRegistry,
+          vaultStakePoolUsduTokenAccount,
+          susduMinter,
+          usduMintToken,
+          susduMintToken,
+          vaultState,
+          vaultConfig,
+          susduConfig,
+          10_000_000,
+          minSusduOut
+        );
+      const expectedSusdu: BN = await vaultProgram.methods
+        .previewDeposit(new BN(10_000_000))
+        .accountsStrict(viewAccounts)
+        .view();
+      await expectError(
+        stake(expectedSusdu.toNumber() + 1),
+        vaultProgram,
+        "SlippageExceeded"
+      );
+      const susduBefore = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      await stake(expectedSusdu.toNumber());
+      const susduAfter = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      assert.equal(
+        susduAfter.sub(susduBefore).toString(),
+        expectedSusdu.toString()
+      );
+
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const unstake = async (minUsduOut: number) => {
+        const cooldown = getCooldownPda(
+          vaultProgram,
+          usduMintToken,
+          receiver.publicKey,
+          caller.publicKey,
+          await getNextCooldownIndex(vaultProgram, caller.publicKey)
+        );
+        await UnstakeSusdu(
+          vaultProgram,
+          susduProgram,
+          blacklistHookProgram,
+          caller,
+          susduReceiverSusduTokenAccount.address,
+          receiver.publicKey,
+          beneficiaryUsduTokenAccount.address,
+          susduConfig,
+          vaultConfig,
+          vaultState,
+          vaultSusduTokenAccount,
+          cooldown,
+          accessRegistry,
+          susduRedeemer,
+          susduMintToken,
+          usduMintToken,
+          vaultStakePoolUsduTokenAccount,
+          vaultSiloUsduTokenAccount,
+          expectedSusdu.toNumber(),
+          extraAccountMetaList,
+          getBlacklistEntryPda(blacklistHookProgram, caller.publicKey),
+          getBlacklistEntryPda(blacklistHookProgram, vaultConfig),
+          minUsduOut
+        );
+        return cooldown;
+      };
+      const expectedUsdu: BN = await vaultProgram.methods
+        .previewRedeem(expectedSusdu)
+        .accountsStrict(viewAccounts)
+        .view();
+      await expectError(
+        unstake(expectedUsdu.toNumber() + 1),
+        vaultProgram,
+        "SlippageExceeded"
+      );
+      const cooldown = await unstake(expectedUsdu.toNumber());
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
+      );
+      assert.equal(
+        cooldownAccount.underlyingTokenAmount.toString(),
+        expectedUsdu.toString()
+      );
+    });
+    it("exact mint and exact unstake honour their slippage bounds", async () => {
+      const viewAccounts = {
+        vaultConfig,
+        susduConfig,
+        susduToken: susduMintToken,
+      };
+      // previews round the other way, so the exact amounts sit within one unit above them
+      const susduAmount: BN = await vaultProgram.methods
+        .previewDeposit(new BN(5_000_000))
+        .accountsStrict(viewAccounts)
+        .view();
+      const usduFloor: BN = await vaultProgram.methods
+        .previewRedeem(susduAmount)
+        .accountsStrict(viewAccounts)
+        .view();
+      const mintExact = (maxUsduIn: number) =>
+        MintSusduExact(
+          vaultProgram,
+          susduProgram,
+          beneficiary,
+          susduReceiver,
+          susduReceiverSusduTokenAccount.address,
+          beneficiaryUsduTokenAccount.address,
+          accessRegistry,
+          vaultStakePoolUsduTokenAccount,
+          susduMinter,
+          usduMintToken,
+          susduMintToken,
+          vaultState,
+          vaultConfig,
+          susduConfig,
+          susduAmount.toNumber(),
+          maxUsduIn
+        );
+      await expectError(
+        mintExact(usduFloor.toNumber() - 1),
+        vaultProgram,
+        "SlippageExceeded"
+      );
+      const susduBefore = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      const usduBefore = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      await mintExact(usduFloor.toNumber() + 1);
+      const susduAfter = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      const usduAfter = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      assert.equal(
+        susduAfter.sub(susduBefore).toString(),
+        susduAmount.toString()
+      );
+      const usduSpent = usduBefore.sub(usduAfter);
+      assert.isTrue(usduSpent.gte(usduFloor));
+      assert.isTrue(usduSpent.lte(usduFloor.addn(1)));
+
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const usduAmount = new BN(2_000_000);
+      const susduFloor: BN = await vaultProgram.methods
+        .previewDeposit(usduAmount)
+        .accountsStrict(viewAccounts)
+        .view();
+      const unstakeExact = async (maxSusduIn: number) => {
+        const cooldown = getCooldownPda(
+          vaultProgram,
+          usduMintToken,
+          receiver.publicKey,
+          caller.publicKey,
+          await getNextCooldownIndex(vaultProgram, caller.publicKey)
+        );
+        await UnstakeUsduExact(
+          vaultProgram,
+          susduProgram,
+          blacklistHookProgram,
+          caller,
+          susduReceiverSusduTokenAccount.address,
+          receiver.publicKey,
+          beneficiaryUsduTokenAccount.address,
+          susduConfig,
+          vaultConfig,
+          vaultState,
+          vaultSusduTokenAccount,
+          cooldown,
+          accessRegistry,
+          susduRedeemer,
+          susduMintToken,
+          usduMintToken,
+          vaultStakePoolUsduTokenAccount,
+          vaultSiloUsduTokenAccount,
+          usduAmount.toNumber(),
+          extraAccountMetaList,
+          getBlacklistEntryPda(blacklistHookProgram, caller.publicKey),
+          getBlacklistEntryPda(blacklistHookProgram, vaultConfig),
+          maxSusduIn
+        );
+        return cooldown;
+      };
+      await expectError(
+        unstakeExact(susduFloor.toNumber() - 1),
+        vaultProgram,
+        "SlippageExceeded"
+      );
+      const cooldown = await unstakeExact(susduFloor.toNumber() + 1);
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
+      );
+      assert.equal(
+        cooldownAccount.underlyingTokenAmount.toString(),
+        usduAmount.toString()
+      );
+      const susduBurned = susduAfter.sub(
+        await getTokenBalance(connection, susduReceiverSusduTokenAccount.address)
+      );
+      assert.isTrue(susduBurned.gte(susduFloor));
+      assert.isTrue(susduBurned.lte(susduFloor.addn(1)));
+    });
+    it("views match the vault accounts", async () => {
+      const viewAccounts = {
+        vaultConfig,
+        susduConfig,
+        susduToken: susduMintToken,
+      };
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const susduConfigAccount = await susduProgram.account.susduConfig.fetch(
+        susduConfig
+      );
+      const susduMint = await getMint(
+        connection,
+        susduMintToken,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      // no reward is vesting yet, so the total assets are the staked counter
+      assert.equal(vaultConfigAccount.vestingAmount.toNumber(), 0);
+      const totalAssets = vaultConfigAccount.totalStakedUsduSupply;
+      const totalShares = susduConfigAccount.totalSupply.add(
+        new BN(10).pow(new BN(vaultConfigAccount.decimalsOffset))
+      );
+
+      const unvested: BN = await vaultProgram.methods
+        .unvestedAmount()
+        .accountsStrict(viewAccounts)
+        .view();
+      assert.equal(unvested.toNumber(), 0);
+      const viewTotalAssets: BN = await vaultProgram.methods
+        .totalAssets()
+        .accountsStrict(viewAccounts)
+        .view();
+      assert.equal(viewTotalAssets.toString(), totalAssets.toString());
+
+      const usduAmount = new BN(7_000_000);
+      const shares: BN = await vaultProgram.methods
+        .previewDeposit(usduAmount)
+        .accountsStrict(viewAccounts)
+        .view();
+      assert.equal(
+        shares.toString(),
+        usduAmount.mul(totalShares).div(totalAssets.addn(1)).toString()
+      );
+      const assets: BN = await vaultProgram.methods
+        .previewRedeem(shares)
+        .accountsStrict(viewAccounts)
+        .view();
+      assert.equal(
+        assets.toString(),
+        shares.mul(totalAssets.addn(1)).div(totalShares).toString()
+      );
+      const oneShare = new BN(10).pow(new BN(susduMint.decimals));
+      const sharePrice: BN = await vaultProgram.methods
+        .sharePrice()
+        .accountsStrict(viewAccounts)
+        .view();
+      assert.equal(
+        sharePrice.toString(),
+        oneShare.mul(totalAssets.addn(1)).div(totalShares).toString()
+      );
+
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        beneficiary.publicKey,
+        susduReceiver.publicKey,
+        (await getNextCooldownIndex(vaultProgram, susduReceiver.publicKey)) - 1
+      );
+      const cooldownAccount = await vaultProgram.account.cooldown.fetch(
+        cooldown
+      );
+      const status = await vaultProgram.methods
+        .cooldownStatus()
+        .accountsStrict({ cooldown })
+        .view();
+      assert.ok(status.owner.equals(cooldownAccount.owner));
+      assert.equal(
+        status.underlyingTokenAmount.toString(),
+        cooldownAccount.underlyingTokenAmount.toString()
+      );
+      assert.equal(
+        status.cooldownEnd.toString(),
+        cooldownAccount.cooldownEnd.toString()
+      );
+      // testnet builds have no cooldown, the ticket is already claimable
+      assert.isFalse(status.isActive);
+      assert.equal(status.remainingSeconds.toNumber(), 0);
+    });
+    it("no-cooldown mode swaps the cooldown for direct redemption", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const susduAmount = new BN(4_000_000);
+      const unstakeDirect = () =>
+        UnstakeSusduDirect(
+          vaultProgram,
+          susduProgram,
+          blacklistHookProgram,
+          caller,
+          susduReceiverSusduTokenAccount.address,
+          receiver.publicKey,
+          beneficiaryUsduTokenAccount.address,
+          susduConfig,
+          vaultConfig,
+          vaultState,
+          vaultSusduTokenAccount,
+          accessRegistry,
+          susduRedeemer,
+          susduMintToken,
+          usduMintToken,
+          vaultStakePoolUsduTokenAccount,
+          susduAmount.toNumber(),
+          extraAccountMetaList,
+          getBlacklistEntryPda(blacklistHookProgram, caller.publicKey),
+          getBlacklistEntryPda(blacklistHookProgram, vaultConfig)
+        );
+      const setNoCooldownMode = async (noCooldownMode: boolean) => {
+        await SetNoCooldownMode(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          vaultState,
+          accessRegistry,
+          vaultAdmin,
+          noCooldownMode
+        );
+        const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+          vaultConfig
+        );
+        assert.equal(vaultConfigAccount.noCooldownMode, noCooldownMode);
+      };
+
+      await expectError(unstakeDirect(), vaultProgram, "CooldownEnabled");
+
+      await setNoCooldownMode(true);
+      await expectError(
+        UnstakeSusdu(
+          vaultProgram,
+          susduProgram,
+          blacklistHookProgram,
+          caller,
+          susduReceiverSusduTokenAccount.address,
+          receiver.publicKey,
+          beneficiaryUsduTokenAccount.address,
+          susduConfig,
+          vaultConfig,
+          vaultState,
+          vaultSusduTokenAccount,
+          getCooldownPda(
+            vaultProgram,
+            usduMintToken,
+            receiver.publicKey,
+            caller.publicKey,
+            await getNextCooldownIndex(vaultProgram, caller.publicKey)
+          ),
+          accessRegistry,
+          susduRedeemer,
+          susduMintToken,
+          usduMintToken,
+          vaultStakePoolUsduTokenAccount,
+          vaultSiloUsduTokenAccount,
+          susduAmount.toNumber(),
+          extraAccountMetaList,
+          getBlacklistEntryPda(blacklistHookProgram, caller.publicKey),
+          getBlacklistEntryPda(blacklistHookProgram, vaultConfig)
+        ),
+        vaultProgram,
+        "CooldownDisabled"
+      );
+
+      const usduAmount: BN = await vaultProgram.methods
+        .previewRedeem(susduAmount)
+        .accountsStrict({
+          vaultConfig,
+          susduConfig,
+          susduToken: susduMintToken,
+        })
+        .view();
+      const vaultConfigBefore = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const susduConfigBefore = await susduProgram.account.susduConfig.fetch(
+        susduConfig
+      );
+      const callerSusduBefore = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      const receiverUsduBefore = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      await unstakeDirect();
+      const vaultConfigAfter = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const susduConfigAfter = await susduProgram.account.susduConfig.fetch(
+        susduConfig
+      );
+      const callerSusduAfter = await getTokenBalance(
+        connection,
+        susduReceiverSusduTokenAccount.address
+      );
+      const receiverUsduAfter = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      // the SUSDU is burned and the USDU paid out of the stake pool right away
+      assert.equal(
+        callerSusduBefore.sub(callerSusduAfter).toString(),
+        susduAmount.toString()
+      );
+      assert.equal(
+        susduConfigBefore.totalSupply.sub(susduConfigAfter.totalSupply).toString(),
+        susduAmount.toString()
+      );
+      assert.equal(
+        receiverUsduAfter.sub(receiverUsduBefore).toString(),
+        usduAmount.toString()
+      );
+      assert.equal(
+        vaultConfigBefore.totalStakedUsduSupply
+          .sub(vaultConfigAfter.totalStakedUsduSupply)
+          .toString(),
+        usduAmount.toString()
+      );
+
+      await setNoCooldownMode(false);
+    });
+    it("cancel cooldown is refused in no-cooldown mode", async () => {
+      const caller = susduReceiver;
+      const receiver = beneficiary;
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        (await getNextCooldownIndex(vaultProgram, caller.publicKey)) - 1
+      );
+      const before = await vaultProgram.account.cooldown.fetch(cooldown);
+
+      await SetNoCooldownMode(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        accessRegistry,
+        vaultAdmin,
+        true
+      );
+      await expectError(
+        CancelCooldown(
+          vaultProgram,
+          susduProgram,
+          caller,
+          susduReceiverSusduTokenAccount.address,
+          receiver.publicKey,
+          cooldown,
+          vaultConfig,
+          vaultState,
+          vaultSiloUsduTokenAccount,
+          vaultStakePoolUsduTokenAccount,
+          accessRegistry,
+          susduMinter,
+          susduConfig,
+          susduMintToken,
+          usduMintToken,
+          before.underlyingTokenAmount.toNumber()
+        ),
+        vaultProgram,
+        "CooldownDisabled"
+      );
+      const after = await vaultProgram.account.cooldown.fetch(cooldown);
+      assert.equal(
+        after.underlyingTokenAmount.toString(),
+        before.underlyingTokenAmount.toString()
+      );
+
+      await SetNoCooldownMode(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        accessRegistry,
+        vaultAdmin,
+        false
+      );
+    });
+    it("report a loss within the cap, once per interval", async () => {
+      const lossReporter = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        fund.publicKey,
+        "loss_reporter"
+      );
+      const reportLoss = (usduAmount: number) =>
+        ReportLoss(
+          vaultProgram,
+          usduProgram,
+          fund,
+          vaultConfig,
+          vaultState,
+          vaultStakePoolUsduTokenAccount,
+          usduConfig,
+          accessRegistry,
+          usduRedeemer,
+          lossReporter,
+          usduMintToken,
+          usduAmount
+        );
+
+      const before = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+      // nothing is vesting, so the cap applies to the whole staked counter
+      assert.equal(before.vestingAmount.toNumber(), 0);
+      const maxLoss = before.totalStakedUsduSupply
+        .muln(before.maxLossPerReportBps)
+        .divn(10_000)
+        .toNumber();
+      await expectError(
+        reportLoss(maxLoss + 1),
+        vaultProgram,
+        "LossExceedsMaxPerReport"
+      );
+
+      const lossAmount = Math.floor(maxLoss / 2);
+      const stakePoolBefore = await getTokenBalance(
+        connection,
+        vaultStakePoolUsduTokenAccount
+      );
+      const usduConfigBefore = await usduProgram.account.usduConfig.fetch(
+        usduConfig
+      );
+      const usduMintBefore = await getMint(
+        connection,
+        usduMintToken,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await reportLoss(lossAmount);
+      const after = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+      const stakePoolAfter = await getTokenBalance(
+        connection,
+        vaultStakePoolUsduTokenAccount
+      );
+      const usduConfigAfter = await usduProgram.account.usduConfig.fetch(
+        usduConfig
+      );
+      const usduMintAfter = await getMint(
+        connection,
+        usduMintToken,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        before.totalStakedUsduSupply.sub(after.totalStakedUsduSupply).toNumber(),
+        lossAmount
+      );
+      assert.equal(stakePoolBefore.sub(stakePoolAfter).toNumber(), lossAmount);
+      // the loss is burned, not moved to another account
+      assert.equal(
+        usduConfigBefore.totalSupply.sub(usduConfigAfter.totalSupply).toNumber(),
+        lossAmount
+      );
+      assert.equal(
+        (usduMintBefore.supply - usduMintAfter.supply).toString(),
+        lossAmount.toString()
+      );
+
+      await expectError(reportLoss(1), vaultProgram, "LossReportTooSoon");
+    });
+    it("stake usdu and mint susdu again", async () => {
+      const caller = beneficiary;
+      await StakeUsduMintSusdu(
         vaultProgram,
         susduProgram,
         caller,
@@ -852,6 +2214,20 @@ describe("Stablecoin Preparation", () => {
         `After stake usdu, susduConfigAccount TotalSupply: ${susduConfigAccount.totalSupply}`
       );
     });
+    it("set the reward vesting period", async () => {
+      await SetVestingPeriod(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        4 * 60 * 60
+      );
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.vestingPeriod.toNumber(), 4 * 60 * 60);
+    });
     it("distribute usdu reward", async () => {
       const caller = beneficiary;
       const callerUsduTokenAccount = beneficiaryUsduTokenAccount.address;
@@ -862,6 +2238,41 @@ describe("Stablecoin Preparation", () => {
         beneficiary.publicKey,
         "reward_distributor"
       );
+      // 10% of each reward is skimmed to the treasury
+      const treasuryUsduTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin,
+        usduMintToken,
+        admin.publicKey,
+        true,
+        "confirmed",
+        {
+          skipPreflight: true,
+          commitment: "confirmed",
+        },
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      await SetRewardFee(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        treasuryUsduTokenAccount.address,
+        accessRegistry,
+        vaultAdmin,
+        1_000
+      );
+      const usduAmount = 100_100_000;
+      const feeAmount = (usduAmount * 1_000) / 10_000;
+      const netAmount = usduAmount - feeAmount;
+
+      const before = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+      const treasuryBefore = await getAccount(
+        connection,
+        treasuryUsduTokenAccount.address,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
       await DistributeUsduReward(
         vaultProgram,
         caller,
@@ -874,7 +2285,106 @@ describe("Stablecoin Preparation", () => {
         usduMintToken,
         susduMintToken,
         susduConfig,
-        100_100_000
+        usduAmount,
+        treasuryUsduTokenAccount.address
+      );
+      const after = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+      const treasuryAfter = await getAccount(
+        connection,
+        treasuryUsduTokenAccount.address,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        (treasuryAfter.amount - treasuryBefore.amount).toString(),
+        feeAmount.toString()
+      );
+      assert.equal(
+        after.totalStakedUsduSupply.sub(before.totalStakedUsduSupply).toNumber(),
+        netAmount
+      );
+      // nothing was vesting before, so the whole net amount vests
+      assert.equal(after.vestingAmount.toNumber(), netAmount);
+    });
+    it("vesting period is locked while rewards vest", async () => {
+      let refused = false;
+      try {
+        await SetVestingPeriod(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          accessRegistry,
+          vaultAdmin,
+          24 * 60 * 60
+        );
+      } catch (error) {
+        refused = true;
+      }
+      assert.isTrue(refused, "the period cannot change mid-schedule");
+      const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(vaultConfigAccount.vestingPeriod.toNumber(), 4 * 60 * 60);
+    });
+    it("a second reward inside the vesting window merges into the schedule", async () => {
+      const [distributeRewarder] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(accessRoleSeed),
+          accessRegistry.toBuffer(),
+          beneficiary.publicKey.toBuffer(),
+          roleToBytes("reward_distributor"),
+        ],
+        guardianProgram.programId
+      );
+      const usduAmount = 50_000_000;
+      const netAmount = usduAmount - (usduAmount * 1_000) / 10_000;
+
+      const before = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+      assert.isTrue(before.vestingAmount.gtn(0));
+      // no StillVesting: the top-up is accepted mid-schedule
+      await DistributeUsduReward(
+        vaultProgram,
+        beneficiary,
+        vaultConfig,
+        vaultState,
+        beneficiaryUsduTokenAccount.address,
+        vaultStakePoolUsduTokenAccount,
+        accessRegistry,
+        distributeRewarder,
+        usduMintToken,
+        susduMintToken,
+        susduConfig,
+        usduAmount,
+        getAssociatedTokenAddressSync(
+          usduMintToken,
+          admin.publicKey,
+          true,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+      const after = await vaultProgram.account.vaultConfig.fetch(vaultConfig);
+
+      // what was still unvested when the second reward landed, as get_unvested_amount computes it
+      const elapsed = after.lastDistributionTimestamp.sub(
+        before.lastDistributionTimestamp
+      );
+      assert.isTrue(elapsed.lt(before.vestingPeriod));
+      const unvested = before.vestingPeriod
+        .sub(elapsed)
+        .mul(before.vestingAmount)
+        .div(before.vestingPeriod);
+      assert.equal(
+        after.vestingAmount.toString(),
+        unvested.addn(netAmount).toString()
+      );
+      assert.equal(
+        after.totalStakedUsduSupply.sub(before.totalStakedUsduSupply).toNumber(),
+        netAmount
+      );
+      // total_assets() right before and right after the call are the same
+      assert.equal(
+        after.totalStakedUsduSupply.sub(after.vestingAmount).toString(),
+        before.totalStakedUsduSupply.sub(unvested).toString()
       );
     });
     it("stake usdu and mint susdu again and again", async () => {
@@ -946,14 +2456,12 @@ describe("Stablecoin Preparation", () => {
       const callerSusduTokenAccount = susduReceiverSusduTokenAccount.address;
       const receiver = beneficiary;
       const receiverUsduTokenAccount = beneficiaryUsduTokenAccount.address;
-      const [cooldown] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(vaultCooldownSeed),
-          Buffer.from(usduMintToken.toBuffer()),
-          Buffer.from(receiver.publicKey.toBuffer()),
-          Buffer.from(caller.publicKey.toBuffer()),
-        ],
-        vaultProgram.programId
+      const cooldown = getCooldownPda(
+        vaultProgram,
+        usduMintToken,
+        receiver.publicKey,
+        caller.publicKey,
+        await getNextCooldownIndex(vaultProgram, caller.publicKey)
       );
       let sourceTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, caller.publicKey);
       let destinationTokenBlacklistAccount = getBlacklistEntryPda(blacklistHookProgram, vaultConfig);
@@ -1000,6 +2508,209 @@ describe("Stablecoin Preparation", () => {
         `After unstake susdu, cooldownAccount.underlyingTokenAmount: ${cooldownAccount.underlyingTokenAmount}`
       );
     });
+    it("queue and cancel a timelocked action", async () => {
+      // raising a delay takes effect at once
+      await SetActionDelay(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        { adjustCooldown: {} },
+        24 * 60 * 60
+      );
+      const pendingAction = await QueueAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        { adjustCooldown: { cooldownDuration: new BN(3 * 24 * 60 * 60) } }
+      );
+      const pendingActionAccount =
+        await vaultProgram.account.pendingAction.fetch(pendingAction);
+      assert.isTrue(
+        pendingActionAccount.eta.gt(pendingActionAccount.queuedAt),
+        "the action waits out its delay"
+      );
+      await expectError(
+        ExecuteAction(
+          vaultProgram,
+          admin,
+          vaultConfig,
+          vaultState,
+          pendingAction,
+          accessRegistry,
+          vaultAdmin
+        ),
+        vaultProgram,
+        "ActionNotReady"
+      );
+      assert.isNotNull(
+        await vaultProgram.account.pendingAction.fetchNullable(pendingAction)
+      );
+
+      const actionCanceller = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        beneficiary.publicKey,
+        "action_canceller"
+      );
+      await CancelAction(
+        vaultProgram,
+        beneficiary,
+        vaultConfig,
+        pendingAction,
+        accessRegistry,
+        actionCanceller
+      );
+      assert.isNull(
+        await vaultProgram.account.pendingAction.fetchNullable(pendingAction)
+      );
+    });
+    it("counter resets stay with the vault config admin", async () => {
+      const otherVaultAdmin = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        beneficiary.publicKey,
+        "vault_admin"
+      );
+      let vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const resetAction = {
+        resetTotalCooldownUsduAmount: {
+          newAmount: vaultConfigAccount.totalCooldownUsduAmount,
+        },
+      };
+
+      // another vault_admin holder can neither queue nor execute it
+      await expectError(
+        QueueAction(
+          vaultProgram,
+          beneficiary,
+          vaultConfig,
+          accessRegistry,
+          otherVaultAdmin,
+          resetAction
+        ),
+        vaultProgram,
+        "InvalidVaultAdminAuthority"
+      );
+      const pendingAction = await QueueAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        resetAction
+      );
+      await expectError(
+        ExecuteAction(
+          vaultProgram,
+          beneficiary,
+          vaultConfig,
+          vaultState,
+          pendingAction,
+          accessRegistry,
+          otherVaultAdmin
+        ),
+        vaultProgram,
+        "InvalidVaultAdminAuthority"
+      );
+
+      // the delay is 0 in testnet builds, so the admin executes it right away
+      await ExecuteAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        pendingAction,
+        accessRegistry,
+        vaultAdmin
+      );
+      assert.isNull(
+        await vaultProgram.account.pendingAction.fetchNullable(pendingAction)
+      );
+      vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      assert.equal(
+        vaultConfigAccount.totalCooldownUsduAmount.toString(),
+        resetAction.resetTotalCooldownUsduAmount.newAmount.toString()
+      );
+    });
+    it("emergency silo withdrawal lowers the cooldown counter", async () => {
+      const vaultConfigBefore = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const siloBefore = await getTokenBalance(
+        connection,
+        vaultSiloUsduTokenAccount
+      );
+      const receiverBefore = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      // the cooldown left open above keeps usdu in the silo
+      const amount = vaultConfigBefore.totalCooldownUsduAmount.divn(2);
+      assert.isTrue(amount.gtn(0));
+
+      const pendingAction = await QueueAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        accessRegistry,
+        vaultAdmin,
+        {
+          emergencyWithdrawVaultSiloUsdu: {
+            receiver: beneficiary.publicKey,
+            amount,
+          },
+        }
+      );
+      await ExecuteAction(
+        vaultProgram,
+        admin,
+        vaultConfig,
+        vaultState,
+        pendingAction,
+        accessRegistry,
+        vaultAdmin,
+        vaultSiloUsduTokenAccount,
+        beneficiaryUsduTokenAccount.address,
+        usduMintToken
+      );
+
+      const vaultConfigAfter = await vaultProgram.account.vaultConfig.fetch(
+        vaultConfig
+      );
+      const siloAfter = await getTokenBalance(
+        connection,
+        vaultSiloUsduTokenAccount
+      );
+      const receiverAfter = await getTokenBalance(
+        connection,
+        beneficiaryUsduTokenAccount.address
+      );
+      assert.equal(
+        vaultConfigBefore.totalCooldownUsduAmount
+          .sub(vaultConfigAfter.totalCooldownUsduAmount)
+          .toString(),
+        amount.toString()
+      );
+      assert.equal(siloBefore.sub(siloAfter).toString(), amount.toString());
+      assert.equal(
+        receiverAfter.sub(receiverBefore).toString(),
+        amount.toString()
+      );
+      assert.equal(
+        vaultConfigAfter.totalStakedUsduSupply.toString(),
+        vaultConfigBefore.totalStakedUsduSupply.toString()
+      );
+    });
     it("adjust blacklist", async () => {
       const user = susduReceiver.publicKey;
       await AddToBlacklist(
@@ -1053,5 +2764,185 @@ describe("Stablecoin Preparation", () => {
         newSusduReceiver.publicKey
       );
     });
+    it("multisig admin assigns a role and hands the admin back", async () => {
+      const guardianAdminRole = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        beneficiary.publicKey,
+        "guardian_admin"
+      );
+      const multisig = await SetMultisig(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        [admin.publicKey, beneficiary.publicKey],
+        2
+      );
+      let accessRegistryAccount =
+        await guardianProgram.account.accessRegistry.fetch(accessRegistry);
+      assert.ok(accessRegistryAccount.admin.equals(multisig));
+
+      // the former admin key alone can no longer assign roles
+      const rejectedRole = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        admin,
+        Keypair.generate().publicKey,
+        "pauser"
+      );
+      assert.isNull(
+        await guardianProgram.account.accessRole.fetchNullable(rejectedRole)
+      );
+
+      // neither can a guardian admin role holder
+      const rejectedByRoleHolder = await AssignRole(
+        guardianProgram,
+        accessRegistry,
+        beneficiary,
+        Keypair.generate().publicKey,
+        "pauser"
+      );
+      assert.isNull(
+        await guardianProgram.account.accessRole.fetchNullable(
+          rejectedByRoleHolder
+        )
+      );
+
+      // 2 of 2: the proposer approves on creation, the second signer completes it
+      const assignProposal = await CreateProposal(
+        guardianProgram,
+        multisig,
+        admin,
+        {
+          assignRole: {
+            user: beneficiary.publicKey,
+            role: getRole("pauser"),
+          },
+        }
+      );
+      await ApproveProposal(
+        guardianProgram,
+        multisig,
+        assignProposal,
+        beneficiary
+      );
+      const pauser = await ExecuteAssignRole(
+        guardianProgram,
+        accessRegistry,
+        multisig,
+        assignProposal,
+        beneficiary,
+        beneficiary.publicKey,
+        "pauser"
+      );
+      const pauserAccount = await guardianProgram.account.accessRole.fetch(
+        pauser
+      );
+      assert.isTrue(pauserAccount.isInitialized);
+
+      const revokeProposal = await CreateProposal(
+        guardianProgram,
+        multisig,
+        admin,
+        { revokeRole: { accessRole: guardianAdminRole } }
+      );
+      await ApproveProposal(
+        guardianProgram,
+        multisig,
+        revokeProposal,
+        beneficiary
+      );
+      await ExecuteRevokeRole(
+        guardianProgram,
+        accessRegistry,
+        multisig,
+        revokeProposal,
+        admin,
+        guardianAdminRole
+      );
+      assert.isNull(
+        await guardianProgram.account.accessRole.fetchNullable(
+          guardianAdminRole
+        )
+      );
+
+      // only its proposer can drop a proposal of the current signer set
+      const staleProposal = await CreateProposal(
+        guardianProgram,
+        multisig,
+        admin,
+        { proposeNewAdmin: { proposedAdmin: beneficiary.publicKey } }
+      );
+      await expectError(
+        CloseProposal(guardianProgram, multisig, staleProposal, beneficiary),
+        guardianProgram,
+        "UnauthorizedProposalClose"
+      );
+
+      // rotate the signer set: the admin key leaves, 1 of 2
+      const newSigner = Keypair.generate().publicKey;
+      let multisigAccount = await guardianProgram.account.multisig.fetch(
+        multisig
+      );
+      const seqno = multisigAccount.signerSetSeqno;
+      const signersProposal = await CreateProposal(
+        guardianProgram,
+        multisig,
+        admin,
+        {
+          updateSigners: {
+            signers: [beneficiary.publicKey, newSigner],
+            threshold: 1,
+          },
+        }
+      );
+      await ApproveProposal(
+        guardianProgram,
+        multisig,
+        signersProposal,
+        beneficiary
+      );
+      await ExecuteUpdateSigners(
+        guardianProgram,
+        accessRegistry,
+        multisig,
+        signersProposal,
+        beneficiary
+      );
+      multisigAccount = await guardianProgram.account.multisig.fetch(multisig);
+      assert.equal(multisigAccount.threshold, 1);
+      assert.ok(multisigAccount.signers[0].equals(beneficiary.publicKey));
+      assert.ok(multisigAccount.signers[1].equals(newSigner));
+      assert.equal(multisigAccount.signerSetSeqno, seqno + 1);
+
+      // anyone can clean up a proposal left over from the previous signer set
+      await CloseProposal(guardianProgram, multisig, staleProposal, beneficiary);
+      assert.isNull(
+        await guardianProgram.account.multisigProposal.fetchNullable(
+          staleProposal
+        )
+      );
+
+      // restore the single admin for the other test files
+      const adminProposal = await CreateProposal(
+        guardianProgram,
+        multisig,
+        beneficiary,
+        { proposeNewAdmin: { proposedAdmin: admin.publicKey } }
+      );
+      await ExecuteProposeNewAdmin(
+        guardianProgram,
+        accessRegistry,
+        multisig,
+        adminProposal,
+        admin,
+        admin.publicKey
+      );
+      await AcceptAdminTransfer(guardianProgram, accessRegistry, admin);
+      accessRegistryAccount =
+        await guardianProgram.account.accessRegistry.fetch(accessRegistry);
+      assert.ok(accessRegistryAccount.admin.equals(admin.publicKey));
+    });
   });
 });
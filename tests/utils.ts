@@ -10,11 +10,15 @@ import {
   LAMPORTS_PER_SOL,
   SYSVAR_RENT_PUBKEY,
   ComputeBudgetProgram,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import {
   createAssociatedTokenAccountInstruction,
   createSyncNativeInstruction,
+  getAccount,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   NATIVE_MINT,
   TOKEN_2022_PROGRAM_ID,
   createCloseAccountInstruction,
@@ -26,16 +30,26 @@ import {
 } from "@solana/spl-token";
 import {
   accessRoleSeed,
+  multisigSeed,
+  multisigProposalSeed,
   roleToBytes,
   getRole,
   blacklistHookConfigSeed,
   blacklistHookExtraAccountMetaListSeed,
   blacklistEntrySeed,
+  vaultCooldownSeed,
+  vaultCooldownCounterSeed,
+  vaultDeadSharesSeed,
+  vaultPendingActionSeed,
+  vaultOrderNonceSeed,
+  mintOrderDomain,
+  redeemOrderDomain,
 } from "./constants";
 import { Vault } from "../target/types/vault";
 import { Usdu } from "../target/types/usdu";
 import { Susdu } from "../target/types/susdu";
 import { BlacklistHook } from "../target/types/blacklist_hook";
+import { assert } from "chai";
 
 export async function AirdropSol(
   connection: Connection,
@@ -115,6 +129,98 @@ export function getBlacklistEntryPda(
   )[0];
 }
 
+export function getCooldownCounterPda(
+  vaultProgram: Program<Vault>,
+  caller: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(vaultCooldownCounterSeed), caller.toBuffer()],
+    vaultProgram.programId
+  )[0];
+}
+
+export function getDeadSharesOwnerPda(vaultProgram: Program<Vault>): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(vaultDeadSharesSeed)],
+    vaultProgram.programId
+  )[0];
+}
+
+export function getPendingActionPda(
+  vaultProgram: Program<Vault>,
+  id: BN
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(vaultPendingActionSeed), id.toArrayLike(Buffer, "le", 8)],
+    vaultProgram.programId
+  )[0];
+}
+
+export function getCooldownPda(
+  vaultProgram: Program<Vault>,
+  usduToken: PublicKey,
+  receiver: PublicKey,
+  caller: PublicKey,
+  index: number
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(vaultCooldownSeed),
+      usduToken.toBuffer(),
+      receiver.toBuffer(),
+      caller.toBuffer(),
+      new BN(index).toArrayLike(Buffer, "le", 8),
+    ],
+    vaultProgram.programId
+  )[0];
+}
+
+export async function getNextCooldownIndex(
+  vaultProgram: Program<Vault>,
+  caller: PublicKey
+): Promise<number> {
+  const counter = await vaultProgram.account.cooldownCounter.fetchNullable(
+    getCooldownCounterPda(vaultProgram, caller)
+  );
+  return counter ? counter.nextIndex.toNumber() : 0;
+}
+
+export async function getTokenBalance(
+  connection: Connection,
+  tokenAccount: PublicKey
+): Promise<BN> {
+  const account = await getAccount(
+    connection,
+    tokenAccount,
+    "confirmed",
+    TOKEN_2022_PROGRAM_ID
+  );
+  return new BN(account.amount.toString());
+}
+
+/// Resolves once `promise` fails with the program error `errorName`
+export async function expectError(
+  promise: Promise<unknown>,
+  program: Program<any>,
+  errorName: string
+) {
+  const idlError = program.idl.errors.find(
+    (e) => e.name.toLowerCase() === errorName.toLowerCase()
+  );
+  try {
+    await promise;
+  } catch (error) {
+    const message = `${error} ${error.logs ? error.logs.join(" ") : ""}`;
+    assert.isTrue(
+      message.includes(idlError.name) ||
+        message.includes(`"Custom":${idlError.code}`),
+      `expected ${errorName}, got ${message}`
+    );
+    return;
+  }
+  assert.fail(`expected ${errorName}`);
+}
+
 export async function InitializeBlacklistHook(
   blacklistHookProgram: Program<BlacklistHook>,
   admin: Keypair,
@@ -232,6 +338,257 @@ export async function AssignRole(
   return assignRole;
 }
 
+export function getMultisigPda(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(multisigSeed), accessRegistry.toBuffer()],
+    guardianProgram.programId
+  )[0];
+}
+
+export function getMultisigProposalPda(
+  guardianProgram: Program<Guardian>,
+  multisig: PublicKey,
+  id: BN
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(multisigProposalSeed),
+      multisig.toBuffer(),
+      id.toArrayLike(Buffer, "le", 8),
+    ],
+    guardianProgram.programId
+  )[0];
+}
+
+export async function SetMultisig(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  admin: Keypair,
+  signers: PublicKey[],
+  threshold: number
+): Promise<PublicKey> {
+  const multisig = getMultisigPda(guardianProgram, accessRegistry);
+  const tx = await guardianProgram.methods
+    .setMultisig(signers, threshold)
+    .accountsStrict({
+      admin: admin.publicKey,
+      accessRegistry: accessRegistry,
+      multisig: multisig,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([admin])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Multisig Transaction signature:", tx);
+  return multisig;
+}
+
+export async function CreateProposal(
+  guardianProgram: Program<Guardian>,
+  multisig: PublicKey,
+  proposer: Keypair,
+  action: any
+): Promise<PublicKey> {
+  const multisigAccount = await guardianProgram.account.multisig.fetch(
+    multisig
+  );
+  const proposal = getMultisigProposalPda(
+    guardianProgram,
+    multisig,
+    multisigAccount.nextProposalId
+  );
+  const tx = await guardianProgram.methods
+    .createProposal(action)
+    .accountsStrict({
+      proposer: proposer.publicKey,
+      multisig: multisig,
+      proposal: proposal,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([proposer])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Create Proposal Transaction signature:", tx);
+  return proposal;
+}
+
+export async function ApproveProposal(
+  guardianProgram: Program<Guardian>,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  signer: Keypair
+) {
+  const tx = await guardianProgram.methods
+    .approveProposal()
+    .accountsStrict({
+      signer: signer.publicKey,
+      multisig: multisig,
+      proposal: proposal,
+    })
+    .signers([signer])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Approve Proposal Transaction signature:", tx);
+}
+
+export async function CloseProposal(
+  guardianProgram: Program<Guardian>,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  closer: Keypair
+) {
+  const proposalAccount =
+    await guardianProgram.account.multisigProposal.fetch(proposal);
+  const tx = await guardianProgram.methods
+    .closeProposal()
+    .accountsStrict({
+      closer: closer.publicKey,
+      multisig: multisig,
+      proposal: proposal,
+      proposer: proposalAccount.proposer,
+    })
+    .signers([closer])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Close Proposal Transaction signature:", tx);
+}
+
+export async function ExecuteAssignRole(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  executor: Keypair,
+  user: PublicKey,
+  role: string
+): Promise<PublicKey> {
+  const proposalAccount =
+    await guardianProgram.account.multisigProposal.fetch(proposal);
+  const [assignRole] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(accessRoleSeed),
+      accessRegistry.toBuffer(),
+      user.toBuffer(),
+      roleToBytes(role),
+    ],
+    guardianProgram.programId
+  );
+  const tx = await guardianProgram.methods
+    .executeAssignRole(getRole(role))
+    .accountsStrict({
+      executor: executor.publicKey,
+      accessRegistry: accessRegistry,
+      multisig: multisig,
+      proposal: proposal,
+      proposer: proposalAccount.proposer,
+      user: user,
+      assignRole: assignRole,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([executor])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Execute Assign Role Transaction signature:", tx);
+  return assignRole;
+}
+
+export async function ExecuteRevokeRole(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  executor: Keypair,
+  revokeRole: PublicKey
+) {
+  const proposalAccount =
+    await guardianProgram.account.multisigProposal.fetch(proposal);
+  const tx = await guardianProgram.methods
+    .executeRevokeRole()
+    .accountsStrict({
+      executor: executor.publicKey,
+      accessRegistry: accessRegistry,
+      multisig: multisig,
+      proposal: proposal,
+      proposer: proposalAccount.proposer,
+      revokeRole: revokeRole,
+    })
+    .signers([executor])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Execute Revoke Role Transaction signature:", tx);
+}
+
+export async function ExecuteProposeNewAdmin(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  executor: Keypair,
+  proposedAdmin: PublicKey
+) {
+  const proposalAccount =
+    await guardianProgram.account.multisigProposal.fetch(proposal);
+  const tx = await guardianProgram.methods
+    .executeProposeNewAdmin()
+    .accountsStrict({
+      executor: executor.publicKey,
+      accessRegistry: accessRegistry,
+      multisig: multisig,
+      proposal: proposal,
+      proposer: proposalAccount.proposer,
+      proposedAdmin: proposedAdmin,
+    })
+    .signers([executor])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Execute Propose New Admin Transaction signature:", tx);
+}
+
+export async function ExecuteUpdateSigners(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  executor: Keypair
+) {
+  const proposalAccount =
+    await guardianProgram.account.multisigProposal.fetch(proposal);
+  const tx = await guardianProgram.methods
+    .executeUpdateSigners()
+    .accountsStrict({
+      executor: executor.publicKey,
+      accessRegistry: accessRegistry,
+      multisig: multisig,
+      proposal: proposal,
+      proposer: proposalAccount.proposer,
+    })
+    .signers([executor])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Execute Update Signers Transaction signature:", tx);
+}
+
+export async function AcceptAdminTransfer(
+  guardianProgram: Program<Guardian>,
+  accessRegistry: PublicKey,
+  newAdmin: Keypair
+) {
+  const tx = await guardianProgram.methods
+    .acceptAdminTransfer()
+    .accountsStrict({
+      newAdmin: newAdmin.publicKey,
+      accessRegistry: accessRegistry,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([newAdmin])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Accept Admin Transfer Transaction signature:", tx);
+}
+
 export async function InitAndCreateUSDU(
   usduProgram: Program<Usdu>,
   usdu: PublicKey,
@@ -534,20 +891,144 @@ export async function CreateMintToken(
   console.log("Create mint transaction:", sig);
 }
 
+export async function AddCollateral(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  collateralToken: PublicKey,
+  collateralConfig: PublicKey,
+  maxMintPerTx: number,
+  maxRedeemPerTx: number
+) {
+  const tx = await vaultProgram.methods
+    .addCollateral(new BN(maxMintPerTx), new BN(maxRedeemPerTx))
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+      collateralToken: collateralToken,
+      collateralConfig: collateralConfig,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Add Collateral Transaction signature:", tx);
+}
+
+export async function AddCustodian(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  collateralConfig: PublicKey,
+  fund: PublicKey,
+  custodian: PublicKey,
+  routingWeightBps: number | null
+) {
+  const tx = await vaultProgram.methods
+    .addCustodian(routingWeightBps)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+      collateralConfig: collateralConfig,
+      fund: fund,
+      custodian: custodian,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Add Custodian Transaction signature:", tx);
+}
+
+export async function SetPrimaryParticipant(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  participantManager: PublicKey,
+  wallet: PublicKey,
+  primaryParticipant: PublicKey,
+  canMint: boolean,
+  canRedeem: boolean,
+  expiry: number | null
+) {
+  const tx = await vaultProgram.methods
+    .setPrimaryParticipant(
+      canMint,
+      canRedeem,
+      expiry === null ? null : new BN(expiry)
+    )
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      participantManager: participantManager,
+      wallet: wallet,
+      primaryParticipant: primaryParticipant,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Primary Participant Transaction signature:", tx);
+}
+
+export async function UpdatePriceFeed(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  priceFeedUpdater: PublicKey,
+  collateralConfig: PublicKey,
+  priceFeed: PublicKey,
+  price: number,
+  decimals: number
+) {
+  const tx = await vaultProgram.methods
+    .updatePriceFeed(new BN(price), decimals)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      priceFeedUpdater: priceFeedUpdater,
+      collateralConfig: collateralConfig,
+      priceFeed: priceFeed,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Update Price Feed Transaction signature:", tx);
+}
+
 export async function DepositCollateralAndMintUsdu(
   vaultProgram: Program<Vault>,
   usduProgram: Program<Usdu>,
   authority: Keypair,
   vaultConfig: PublicKey,
+  vaultState: PublicKey,
   usduConfig: PublicKey,
   accessRegistry: PublicKey,
   usduMinter: PublicKey,
   collateralDepositor: PublicKey,
   collateralToken: PublicKey,
+  collateralConfig: PublicKey,
+  priceFeed: PublicKey,
   usduToken: PublicKey,
   benefactor: Keypair,
   beneficiary: Keypair,
   fund: Keypair,
+  custodian: PublicKey,
+  benefactorParticipant: PublicKey,
+  beneficiaryParticipant: PublicKey,
   collateralAmount: number,
   usduAmount: number,
   benefactorCollateralTokenAccount: PublicKey,
@@ -557,6 +1038,7 @@ export async function DepositCollateralAndMintUsdu(
   const tx = await vaultProgram.methods
     .depositCollateralMintUsdu(new BN(collateralAmount), new BN(usduAmount))
     .accountsStrict({
+      vaultState: vaultState,
       vaultConfig: vaultConfig,
       usduConfig: usduConfig,
       authority: authority.publicKey,
@@ -564,10 +1046,15 @@ export async function DepositCollateralAndMintUsdu(
       usduMinter: usduMinter,
       collateralDepositor: collateralDepositor,
       collateralToken: collateralToken,
+      collateralConfig: collateralConfig,
+      priceFeed: priceFeed,
       usduToken: usduToken,
       beneficiary: beneficiary.publicKey,
       benefactor: benefactor.publicKey,
       fund: fund.publicKey,
+      custodian: custodian,
+      benefactorParticipant: benefactorParticipant,
+      beneficiaryParticipant: beneficiaryParticipant,
       benefactorCollateralTokenAccount: benefactorCollateralTokenAccount,
       fundCollateralTokenAccount: fundCollateralTokenAccount,
       beneficiaryUsduTokenAccount: beneficiaryUsduTokenAccount,
@@ -593,10 +1080,15 @@ export async function RedeemUsduAndWithdrawCollateral(
   usduRedeemer: PublicKey,
   collateralWithdrawer: PublicKey,
   collateralToken: PublicKey,
+  collateralConfig: PublicKey,
+  priceFeed: PublicKey,
   usduToken: PublicKey,
   benefactor: Keypair,
   beneficiary: Keypair,
   fund: Keypair,
+  custodian: PublicKey,
+  benefactorParticipant: PublicKey,
+  beneficiaryParticipant: PublicKey,
   collateralAmount: number,
   usduAmount: number,
   beneficiaryUsduTokenAccount: PublicKey,
@@ -615,10 +1107,15 @@ export async function RedeemUsduAndWithdrawCollateral(
       usduRedeemer: usduRedeemer,
       collateralWithdrawer: collateralWithdrawer,
       collateralToken: collateralToken,
+      collateralConfig: collateralConfig,
+      priceFeed: priceFeed,
       usduToken: usduToken,
       benefactor: benefactor.publicKey,
       beneficiary: beneficiary.publicKey,
       fund: fund.publicKey,
+      custodian: custodian,
+      benefactorParticipant: benefactorParticipant,
+      beneficiaryParticipant: beneficiaryParticipant,
       beneficiaryUsduTokenAccount: beneficiaryUsduTokenAccount,
       benefactorCollateralTokenAccount: benefactorCollateralTokenAccount,
       fundCollateralTokenAccount: fundCollateralTokenAccount,
@@ -634,30 +1131,235 @@ export async function RedeemUsduAndWithdrawCollateral(
   console.log("Redeem Usdu and Withdraw Collateral Transaction signature:", tx);
 }
 
-export async function StakeUsduMintSusdu(
+export type UsduOrder = {
+  benefactor: PublicKey;
+  beneficiary: PublicKey;
+  collateralMint: PublicKey;
+  collateralAmount: BN;
+  usduAmount: BN;
+  expiry: BN;
+  nonce: BN;
+};
+
+/// Bytes the benefactor signs, mirrors `MintOrder::message` and `RedeemOrder::message`
+export function getOrderMessage(
   vaultProgram: Program<Vault>,
-  susduProgram: Program<Susdu>,
-  caller: Keypair,
-  receiver: Keypair,
-  receiverSusduTokenAccount: PublicKey,
-  callerUsduTokenAccount: PublicKey,
-  accessRegistry: PublicKey,
-  vaultStakePoolUsduTokenAccount: PublicKey,
-  susduMinter: PublicKey,
-  usduToken: PublicKey,
-  susduToken: PublicKey,
-  vaultState: PublicKey,
-  vaultConfig: PublicKey,
-  susduConfig: PublicKey,
-  usduAmount: number
-) {
-  const tx = await vaultProgram.methods
-    .stakeUsduMintSusdu(new BN(usduAmount))
-    .accountsStrict({
+  domain: string,
+  order: UsduOrder
+): Buffer {
+  return Buffer.concat([
+    Buffer.from(domain),
+    vaultProgram.programId.toBuffer(),
+    order.benefactor.toBuffer(),
+    order.beneficiary.toBuffer(),
+    order.collateralMint.toBuffer(),
+    order.collateralAmount.toArrayLike(Buffer, "le", 8),
+    order.usduAmount.toArrayLike(Buffer, "le", 8),
+    order.expiry.toArrayLike(Buffer, "le", 8),
+    order.nonce.toArrayLike(Buffer, "le", 8),
+  ]);
+}
+
+export function getOrderNonceBitmapPda(
+  vaultProgram: Program<Vault>,
+  benefactor: PublicKey,
+  nonce: BN
+): PublicKey {
+  // one bitmap page covers 16 * 64 nonces
+  const page = nonce.divn(16 * 64);
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(vaultOrderNonceSeed),
+      benefactor.toBuffer(),
+      page.toArrayLike(Buffer, "le", 8),
+    ],
+    vaultProgram.programId
+  )[0];
+}
+
+export async function MintUsduWithOrder(
+  vaultProgram: Program<Vault>,
+  usduProgram: Program<Usdu>,
+  authority: Keypair,
+  payer: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  usduConfig: PublicKey,
+  accessRegistry: PublicKey,
+  usduMinter: PublicKey,
+  collateralDepositor: PublicKey,
+  collateralConfig: PublicKey,
+  priceFeed: PublicKey,
+  usduToken: PublicKey,
+  fund: PublicKey,
+  custodian: PublicKey,
+  benefactorParticipant: PublicKey,
+  beneficiaryParticipant: PublicKey,
+  benefactorCollateralTokenAccount: PublicKey,
+  beneficiaryUsduTokenAccount: PublicKey,
+  fundCollateralTokenAccount: PublicKey,
+  order: UsduOrder,
+  signer: Keypair
+) {
+  // the ed25519 program instruction carrying the benefactor's signature goes right before the fill
+  const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message: getOrderMessage(vaultProgram, mintOrderDomain, order),
+  });
+  const tx = await vaultProgram.methods
+    .mintUsduWithOrder(order)
+    .accountsStrict({
+      deposit: {
+        vaultState: vaultState,
+        vaultConfig: vaultConfig,
+        usduConfig: usduConfig,
+        authority: authority.publicKey,
+        accessRegistry: accessRegistry,
+        usduMinter: usduMinter,
+        collateralDepositor: collateralDepositor,
+        collateralToken: order.collateralMint,
+        collateralConfig: collateralConfig,
+        priceFeed: priceFeed,
+        usduToken: usduToken,
+        beneficiary: order.beneficiary,
+        benefactor: order.benefactor,
+        fund: fund,
+        custodian: custodian,
+        benefactorParticipant: benefactorParticipant,
+        beneficiaryParticipant: beneficiaryParticipant,
+        benefactorCollateralTokenAccount: benefactorCollateralTokenAccount,
+        fundCollateralTokenAccount: fundCollateralTokenAccount,
+        beneficiaryUsduTokenAccount: beneficiaryUsduTokenAccount,
+        usduProgram: usduProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      nonceBitmap: getOrderNonceBitmapPda(
+        vaultProgram,
+        order.benefactor,
+        order.nonce
+      ),
+      payer: payer.publicKey,
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .preInstructions([ed25519Ix])
+    .signers([authority, payer])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Mint Usdu With Order Transaction signature:", tx);
+}
+
+/// `order.benefactor` holds the USDU, in the redeem accounts it is the `beneficiary`
+export async function RedeemUsduWithOrder(
+  vaultProgram: Program<Vault>,
+  usduProgram: Program<Usdu>,
+  authority: Keypair,
+  payer: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  usduConfig: PublicKey,
+  accessRegistry: PublicKey,
+  usduRedeemer: PublicKey,
+  collateralWithdrawer: PublicKey,
+  collateralConfig: PublicKey,
+  priceFeed: PublicKey,
+  usduToken: PublicKey,
+  fund: PublicKey,
+  custodian: PublicKey,
+  benefactorParticipant: PublicKey,
+  beneficiaryParticipant: PublicKey,
+  beneficiaryUsduTokenAccount: PublicKey,
+  fundCollateralTokenAccount: PublicKey,
+  vaultUsduTokenAccount: PublicKey,
+  benefactorCollateralTokenAccount: PublicKey,
+  order: UsduOrder,
+  signer: Keypair
+) {
+  const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message: getOrderMessage(vaultProgram, redeemOrderDomain, order),
+  });
+  const tx = await vaultProgram.methods
+    .redeemUsduWithOrder(order)
+    .accountsStrict({
+      redeem: {
+        vaultConfig: vaultConfig,
+        vaultState: vaultState,
+        usduConfig: usduConfig,
+        authority: authority.publicKey,
+        accessRegistry: accessRegistry,
+        usduRedeemer: usduRedeemer,
+        collateralWithdrawer: collateralWithdrawer,
+        collateralToken: order.collateralMint,
+        collateralConfig: collateralConfig,
+        priceFeed: priceFeed,
+        usduToken: usduToken,
+        benefactor: order.beneficiary,
+        beneficiary: order.benefactor,
+        fund: fund,
+        custodian: custodian,
+        benefactorParticipant: benefactorParticipant,
+        beneficiaryParticipant: beneficiaryParticipant,
+        beneficiaryUsduTokenAccount: beneficiaryUsduTokenAccount,
+        benefactorCollateralTokenAccount: benefactorCollateralTokenAccount,
+        fundCollateralTokenAccount: fundCollateralTokenAccount,
+        vaultUsduTokenAccount: vaultUsduTokenAccount,
+        usduProgram: usduProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      nonceBitmap: getOrderNonceBitmapPda(
+        vaultProgram,
+        order.benefactor,
+        order.nonce
+      ),
+      payer: payer.publicKey,
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .preInstructions([ed25519Ix])
+    .signers([authority, payer])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Redeem Usdu With Order Transaction signature:", tx);
+}
+
+export async function StakeUsduMintSusdu(
+  vaultProgram: Program<Vault>,
+  susduProgram: Program<Susdu>,
+  caller: Keypair,
+  receiver: Keypair,
+  receiverSusduTokenAccount: PublicKey,
+  callerUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  susduMinter: PublicKey,
+  usduToken: PublicKey,
+  susduToken: PublicKey,
+  vaultState: PublicKey,
+  vaultConfig: PublicKey,
+  susduConfig: PublicKey,
+  usduAmount: number,
+  minSusduOut: number = 0
+) {
+  const deadSharesOwner = getDeadSharesOwnerPda(vaultProgram);
+  const tx = await vaultProgram.methods
+    .stakeUsduMintSusdu(new BN(usduAmount), new BN(minSusduOut))
+    .accountsStrict({
       caller: caller.publicKey,
       receiver: receiver.publicKey,
       receiverSusduTokenAccount: receiverSusduTokenAccount,
       callerUsduTokenAccount: callerUsduTokenAccount,
+      deadSharesOwner: deadSharesOwner,
+      deadSharesTokenAccount: getAssociatedTokenAddressSync(
+        susduToken,
+        deadSharesOwner,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      ),
       accessRegistry: accessRegistry,
       vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
       susduMinter: susduMinter,
@@ -677,6 +1379,58 @@ export async function StakeUsduMintSusdu(
   console.log("Stake Usdu and Mint Susdu Transaction signature:", tx);
 }
 
+export async function MintSusduExact(
+  vaultProgram: Program<Vault>,
+  susduProgram: Program<Susdu>,
+  caller: Keypair,
+  receiver: Keypair,
+  receiverSusduTokenAccount: PublicKey,
+  callerUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  susduMinter: PublicKey,
+  usduToken: PublicKey,
+  susduToken: PublicKey,
+  vaultState: PublicKey,
+  vaultConfig: PublicKey,
+  susduConfig: PublicKey,
+  susduAmount: number,
+  maxUsduIn: number
+) {
+  const deadSharesOwner = getDeadSharesOwnerPda(vaultProgram);
+  const tx = await vaultProgram.methods
+    .mintSusduExact(new BN(susduAmount), new BN(maxUsduIn))
+    .accountsStrict({
+      caller: caller.publicKey,
+      receiver: receiver.publicKey,
+      receiverSusduTokenAccount: receiverSusduTokenAccount,
+      callerUsduTokenAccount: callerUsduTokenAccount,
+      deadSharesOwner: deadSharesOwner,
+      deadSharesTokenAccount: getAssociatedTokenAddressSync(
+        susduToken,
+        deadSharesOwner,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      accessRegistry: accessRegistry,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      susduMinter: susduMinter,
+      usduToken: usduToken,
+      susduToken: susduToken,
+      vaultState: vaultState,
+      vaultConfig: vaultConfig,
+      susduConfig: susduConfig,
+      susduProgram: susduProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Mint Susdu Exact Transaction signature:", tx);
+}
+
 export async function UnstakeSusdu(
   vaultProgram: Program<Vault>,
   susduProgram: Program<Susdu>,
@@ -700,6 +1454,7 @@ export async function UnstakeSusdu(
   extraAccountMetaList: PublicKey,
   sourceTokenBlacklistAccount: PublicKey,
   destinationTokenBlacklistAccount: PublicKey,
+  minUsduOut: number = 0,
 ) {
   const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({
     units: 1400000
@@ -709,7 +1464,7 @@ export async function UnstakeSusdu(
   });
   
   const tx = await vaultProgram.methods
-    .unstakeSusdu(new BN(susduAmount))
+    .unstakeSusdu(new BN(susduAmount), new BN(minUsduOut))
     .accountsStrict({
       caller: caller.publicKey,
       callerSusduTokenAccount: callerSusduTokenAccount,
@@ -723,6 +1478,7 @@ export async function UnstakeSusdu(
       vaultConfig: vaultConfig,
       vaultState: vaultState,
       accessRegistry: accessRegistry,
+      cooldownCounter: getCooldownCounterPda(vaultProgram, caller.publicKey),
       cooldown: cooldown,
       susduConfig: susduConfig,
       susduRedeemer: susduRedeemer,
@@ -763,73 +1519,689 @@ export async function UnstakeSusdu(
   console.log("Unstake Susdu Transaction signature:", tx);
 }
 
-export async function WithdrawUsdu(
+export async function UnstakeUsduExact(
   vaultProgram: Program<Vault>,
+  susduProgram: Program<Susdu>,
+  blacklistHookProgram: Program<BlacklistHook>,
   caller: Keypair,
+  callerSusduTokenAccount: PublicKey,
   receiver: PublicKey,
+  receiverUsduTokenAccount: PublicKey,
+  susduConfig: PublicKey,
   vaultConfig: PublicKey,
   vaultState: PublicKey,
-  receiverUsduTokenAccount: PublicKey,
-  vaultSiloUsduTokenAccount: PublicKey,
+  vaultSusduTokenAccount: PublicKey,
   cooldown: PublicKey,
-  usduToken: PublicKey
+  accessRegistry: PublicKey,
+  susduRedeemer: PublicKey,
+  susduToken: PublicKey,
+  usduToken: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  vaultSiloUsduTokenAccount: PublicKey,
+  usduAmount: number,
+  extraAccountMetaList: PublicKey,
+  sourceTokenBlacklistAccount: PublicKey,
+  destinationTokenBlacklistAccount: PublicKey,
+  maxSusduIn: number
 ) {
+  const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({
+    units: 1400000,
+  });
+  const addPriorityFee = ComputeBudgetProgram.setComputeUnitPrice({
+    microLamports: 1,
+  });
+
   const tx = await vaultProgram.methods
-    .withdrawUsdu()
+    .unstakeUsduExact(new BN(usduAmount), new BN(maxSusduIn))
     .accountsStrict({
       caller: caller.publicKey,
+      callerSusduTokenAccount: callerSusduTokenAccount,
       receiver: receiver,
-      vaultConfig: vaultConfig,
-      vaultState: vaultState,
       receiverUsduTokenAccount: receiverUsduTokenAccount,
+      susduToken: susduToken,
+      usduToken: usduToken,
+      vaultSusduTokenAccount: vaultSusduTokenAccount,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
       vaultSiloUsduTokenAccount: vaultSiloUsduTokenAccount,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      accessRegistry: accessRegistry,
+      cooldownCounter: getCooldownCounterPda(vaultProgram, caller.publicKey),
       cooldown: cooldown,
-      usduToken: usduToken,
+      susduConfig: susduConfig,
+      susduRedeemer: susduRedeemer,
+      susduProgram: susduProgram.programId,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts([
+      {
+        pubkey: blacklistHookProgram.programId,
+        isWritable: true,
+        isSigner: false,
+      },
+      {
+        pubkey: extraAccountMetaList,
+        isWritable: false,
+        isSigner: false,
+      },
+      {
+        pubkey: sourceTokenBlacklistAccount,
+        isWritable: false,
+        isSigner: false,
+      },
+      {
+        pubkey: destinationTokenBlacklistAccount,
+        isWritable: false,
+        isSigner: false,
+      },
+    ])
+    .preInstructions([modifyComputeUnits, addPriorityFee])
     .signers([caller])
     .rpc({ skipPreflight: true, commitment: "confirmed" });
 
-  console.log("Withdraw Usdu Transaction signature:", tx);
+  console.log("Unstake Usdu Exact Transaction signature:", tx);
 }
 
-export async function DistributeUsduReward(
+export async function UnstakeSusduDirect(
   vaultProgram: Program<Vault>,
+  susduProgram: Program<Susdu>,
+  blacklistHookProgram: Program<BlacklistHook>,
   caller: Keypair,
+  callerSusduTokenAccount: PublicKey,
+  receiver: PublicKey,
+  receiverUsduTokenAccount: PublicKey,
+  susduConfig: PublicKey,
   vaultConfig: PublicKey,
   vaultState: PublicKey,
-  callerUsduTokenAccount: PublicKey,
-  vaultStakePoolUsduTokenAccount: PublicKey,
+  vaultSusduTokenAccount: PublicKey,
   accessRegistry: PublicKey,
-  rewardDistributor: PublicKey,
-  usduToken: PublicKey,
+  susduRedeemer: PublicKey,
   susduToken: PublicKey,
-  susduConfig: PublicKey,
-  usduAmount: number
+  usduToken: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  susduAmount: number,
+  extraAccountMetaList: PublicKey,
+  sourceTokenBlacklistAccount: PublicKey,
+  destinationTokenBlacklistAccount: PublicKey,
+  minUsduOut: number = 0
 ) {
   const tx = await vaultProgram.methods
-    .distributeUsduReward(new BN(usduAmount))
+    .unstakeSusduDirect(new BN(susduAmount), new BN(minUsduOut))
     .accountsStrict({
       caller: caller.publicKey,
+      callerSusduTokenAccount: callerSusduTokenAccount,
+      receiver: receiver,
+      receiverUsduTokenAccount: receiverUsduTokenAccount,
+      susduConfig: susduConfig,
       vaultConfig: vaultConfig,
       vaultState: vaultState,
-      callerUsduTokenAccount: callerUsduTokenAccount,
-      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      vaultSusduTokenAccount: vaultSusduTokenAccount,
       accessRegistry: accessRegistry,
-      distributeRewarder: rewardDistributor,
-      usduToken: usduToken,
+      susduRedeemer: susduRedeemer,
       susduToken: susduToken,
-      susduConfig: susduConfig,
+      usduToken: usduToken,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      susduProgram: susduProgram.programId,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts([
+      {
+        pubkey: blacklistHookProgram.programId,
+        isWritable: true,
+        isSigner: false,
+      },
+      {
+        pubkey: extraAccountMetaList,
+        isWritable: false,
+        isSigner: false,
+      },
+      {
+        pubkey: sourceTokenBlacklistAccount,
+        isWritable: false,
+        isSigner: false,
+      },
+      {
+        pubkey: destinationTokenBlacklistAccount,
+        isWritable: false,
+        isSigner: false,
+      },
+    ])
     .signers([caller])
     .rpc({ skipPreflight: true, commitment: "confirmed" });
 
-  console.log("Distribute Usdu Reward Transaction signature:", tx);
+  console.log("Unstake Susdu Direct Transaction signature:", tx);
+}
+
+export async function WithdrawUsdu(
+  vaultProgram: Program<Vault>,
+  caller: Keypair,
+  receiver: PublicKey,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  receiverUsduTokenAccount: PublicKey,
+  vaultSiloUsduTokenAccount: PublicKey,
+  cooldown: PublicKey,
+  usduToken: PublicKey,
+  usduAmount: number
+) {
+  const tx = await vaultProgram.methods
+    .withdrawUsdu(new BN(usduAmount))
+    .accountsStrict({
+      caller: caller.publicKey,
+      receiver: receiver,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      receiverUsduTokenAccount: receiverUsduTokenAccount,
+      vaultSiloUsduTokenAccount: vaultSiloUsduTokenAccount,
+      cooldown: cooldown,
+      usduToken: usduToken,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Withdraw Usdu Transaction signature:", tx);
+}
+
+export async function CrankWithdrawUsdu(
+  vaultProgram: Program<Vault>,
+  keeper: Keypair,
+  keeperUsduTokenAccount: PublicKey,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  vaultSiloUsduTokenAccount: PublicKey,
+  usduToken: PublicKey,
+  cooldowns: {
+    cooldown: PublicKey;
+    receiverUsduTokenAccount: PublicKey;
+    owner: PublicKey;
+  }[]
+) {
+  const tx = await vaultProgram.methods
+    .crankWithdrawUsdu()
+    .accountsStrict({
+      keeper: keeper.publicKey,
+      keeperUsduTokenAccount: keeperUsduTokenAccount,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      vaultSiloUsduTokenAccount: vaultSiloUsduTokenAccount,
+      usduToken: usduToken,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      cooldowns.flatMap(({ cooldown, receiverUsduTokenAccount, owner }) => [
+        { pubkey: cooldown, isWritable: true, isSigner: false },
+        { pubkey: receiverUsduTokenAccount, isWritable: true, isSigner: false },
+        { pubkey: owner, isWritable: true, isSigner: false },
+      ])
+    )
+    .signers([keeper])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Crank Withdraw Usdu Transaction signature:", tx);
+}
+
+export async function SetCooldownReceiver(
+  vaultProgram: Program<Vault>,
+  blacklistHookProgram: Program<BlacklistHook>,
+  caller: Keypair,
+  receiver: PublicKey,
+  cooldown: PublicKey,
+  newReceiver: PublicKey,
+  newReceiverUsduTokenAccount: PublicKey,
+  usduToken: PublicKey
+) {
+  const tx = await vaultProgram.methods
+    .setCooldownReceiver()
+    .accountsStrict({
+      caller: caller.publicKey,
+      receiver: receiver,
+      cooldown: cooldown,
+      newReceiver: newReceiver,
+      newReceiverUsduTokenAccount: newReceiverUsduTokenAccount,
+      newReceiverBlacklistEntry: getBlacklistEntryPda(
+        blacklistHookProgram,
+        newReceiver
+      ),
+      usduToken: usduToken,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Cooldown Receiver Transaction signature:", tx);
+}
+
+export async function CloseCooldown(
+  vaultProgram: Program<Vault>,
+  caller: Keypair,
+  receiver: PublicKey,
+  cooldown: PublicKey,
+  vaultConfig: PublicKey
+) {
+  const tx = await vaultProgram.methods
+    .closeCooldown()
+    .accountsStrict({
+      caller: caller.publicKey,
+      receiver: receiver,
+      cooldown: cooldown,
+      vaultConfig: vaultConfig,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Close Cooldown Transaction signature:", tx);
+}
+
+export async function CancelCooldown(
+  vaultProgram: Program<Vault>,
+  susduProgram: Program<Susdu>,
+  caller: Keypair,
+  callerSusduTokenAccount: PublicKey,
+  receiver: PublicKey,
+  cooldown: PublicKey,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  vaultSiloUsduTokenAccount: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  susduMinter: PublicKey,
+  susduConfig: PublicKey,
+  susduToken: PublicKey,
+  usduToken: PublicKey,
+  usduAmount: number,
+  minSusduOut: number = 0,
+) {
+  const tx = await vaultProgram.methods
+    .cancelCooldown(new BN(usduAmount), new BN(minSusduOut))
+    .accountsStrict({
+      caller: caller.publicKey,
+      callerSusduTokenAccount: callerSusduTokenAccount,
+      receiver: receiver,
+      cooldown: cooldown,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      vaultSiloUsduTokenAccount: vaultSiloUsduTokenAccount,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      accessRegistry: accessRegistry,
+      susduMinter: susduMinter,
+      susduConfig: susduConfig,
+      susduToken: susduToken,
+      usduToken: usduToken,
+      susduProgram: susduProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Cancel Cooldown Transaction signature:", tx);
+}
+
+export async function DistributeUsduReward(
+  vaultProgram: Program<Vault>,
+  caller: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  callerUsduTokenAccount: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  rewardDistributor: PublicKey,
+  usduToken: PublicKey,
+  susduToken: PublicKey,
+  susduConfig: PublicKey,
+  usduAmount: number,
+  treasuryUsduTokenAccount: PublicKey | null = null
+) {
+  const tx = await vaultProgram.methods
+    .distributeUsduReward(new BN(usduAmount))
+    .accountsStrict({
+      caller: caller.publicKey,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      callerUsduTokenAccount: callerUsduTokenAccount,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      treasuryUsduTokenAccount: treasuryUsduTokenAccount,
+      accessRegistry: accessRegistry,
+      distributeRewarder: rewardDistributor,
+      usduToken: usduToken,
+      susduToken: susduToken,
+      susduConfig: susduConfig,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([caller])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Distribute Usdu Reward Transaction signature:", tx);
+}
+
+export async function ReportLoss(
+  vaultProgram: Program<Vault>,
+  usduProgram: Program<Usdu>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  usduConfig: PublicKey,
+  accessRegistry: PublicKey,
+  usduRedeemer: PublicKey,
+  lossReporter: PublicKey,
+  usduToken: PublicKey,
+  usduAmount: number
+) {
+  const tx = await vaultProgram.methods
+    .reportLoss(new BN(usduAmount))
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      usduConfig: usduConfig,
+      accessRegistry: accessRegistry,
+      usduRedeemer: usduRedeemer,
+      lossReporter: lossReporter,
+      usduToken: usduToken,
+      usduProgram: usduProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Report Loss Transaction signature:", tx);
+}
+
+export async function SyncVaultAccounting(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  vaultStakePoolUsduTokenAccount: PublicKey,
+  vaultSiloUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey
+) {
+  const tx = await vaultProgram.methods
+    .syncVaultAccounting()
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      vaultStakePoolUsduTokenAccount: vaultStakePoolUsduTokenAccount,
+      vaultSiloUsduTokenAccount: vaultSiloUsduTokenAccount,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Sync Vault Accounting Transaction signature:", tx);
+}
+
+export async function QueueAction(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  action: any
+): Promise<PublicKey> {
+  const vaultConfigAccount = await vaultProgram.account.vaultConfig.fetch(
+    vaultConfig
+  );
+  const pendingAction = getPendingActionPda(
+    vaultProgram,
+    vaultConfigAccount.nextActionId
+  );
+  const tx = await vaultProgram.methods
+    .queueAction(action)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      pendingAction: pendingAction,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Queue Action Transaction signature:", tx);
+  return pendingAction;
+}
+
+export async function CancelAction(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  pendingAction: PublicKey,
+  accessRegistry: PublicKey,
+  actionCanceller: PublicKey
+) {
+  const pendingActionAccount = await vaultProgram.account.pendingAction.fetch(
+    pendingAction
+  );
+  const tx = await vaultProgram.methods
+    .cancelAction()
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      pendingAction: pendingAction,
+      proposer: pendingActionAccount.proposer,
+      accessRegistry: accessRegistry,
+      actionCanceller: actionCanceller,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Cancel Action Transaction signature:", tx);
+}
+
+export async function ExecuteAction(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  pendingAction: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  vaultTokenAccount: PublicKey = null,
+  receiverTokenAccount: PublicKey = null,
+  tokenMint: PublicKey = null
+) {
+  const pendingActionAccount = await vaultProgram.account.pendingAction.fetch(
+    pendingAction
+  );
+  const tx = await vaultProgram.methods
+    .executeAction()
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      vaultState: vaultState,
+      pendingAction: pendingAction,
+      proposer: pendingActionAccount.proposer,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+      vaultTokenAccount: vaultTokenAccount,
+      receiverTokenAccount: receiverTokenAccount,
+      tokenMint: tokenMint,
+      tokenProgram: tokenMint ? TOKEN_2022_PROGRAM_ID : null,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Execute Action Transaction signature:", tx);
+}
+
+/// Queues and applies a no-cooldown mode switch, testnet builds have no action delay
+export async function SetNoCooldownMode(
+  vaultProgram: Program<Vault>,
+  admin: Keypair,
+  vaultConfig: PublicKey,
+  vaultState: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  noCooldownMode: boolean
+) {
+  const action = await QueueAction(
+    vaultProgram,
+    admin,
+    vaultConfig,
+    accessRegistry,
+    vaultAdmin,
+    { setNoCooldownMode: { noCooldownMode } }
+  );
+  await ExecuteAction(
+    vaultProgram,
+    admin,
+    vaultConfig,
+    vaultState,
+    action,
+    accessRegistry,
+    vaultAdmin
+  );
+}
+
+export async function SetActionDelay(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  actionType: any,
+  delay: number
+) {
+  const tx = await vaultProgram.methods
+    .setActionDelay(actionType, new BN(delay))
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Action Delay Transaction signature:", tx);
+}
+
+export async function SetRewardFee(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  treasuryUsduTokenAccount: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  rewardFeeBps: number
+) {
+  const tx = await vaultProgram.methods
+    .setRewardFee(rewardFeeBps)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      treasuryUsduTokenAccount: treasuryUsduTokenAccount,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Reward Fee Transaction signature:", tx);
+}
+
+export async function SetVestingPeriod(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  vestingPeriod: number
+) {
+  const tx = await vaultProgram.methods
+    .setVestingPeriod(new BN(vestingPeriod))
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Vesting Period Transaction signature:", tx);
+}
+
+export async function SetDecimalsOffset(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  vaultAdmin: PublicKey,
+  decimalsOffset: number
+) {
+  const tx = await vaultProgram.methods
+    .setDecimalsOffset(decimalsOffset)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      vaultAdmin: vaultAdmin,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Set Decimals Offset Transaction signature:", tx);
+}
+
+export async function Pause(
+  vaultProgram: Program<Vault>,
+  authority: Keypair,
+  vaultConfig: PublicKey,
+  accessRegistry: PublicKey,
+  pauser: PublicKey,
+  flags: number
+) {
+  const tx = await vaultProgram.methods
+    .pause(flags)
+    .accountsStrict({
+      authority: authority.publicKey,
+      vaultConfig: vaultConfig,
+      accessRegistry: accessRegistry,
+      pauser: pauser,
+    })
+    .signers([authority])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Pause Transaction signature:", tx);
+}
+
+export async function Unpause(
+  vaultProgram: Program<Vault>,
+  admin: Keypair,
+  vaultConfig: PublicKey,
+  flags: number
+) {
+  const tx = await vaultProgram.methods
+    .unpause(flags)
+    .accountsStrict({
+      admin: admin.publicKey,
+      vaultConfig: vaultConfig,
+    })
+    .signers([admin])
+    .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+  console.log("Unpause Transaction signature:", tx);
 }
 
 export async function RedistributeLockedSusdu(